@@ -0,0 +1,24 @@
+//! Grids
+//!
+//! This module provides [`Grid2D`], a dense two-dimensional grid of cells
+//! addressed by [`Vec2D`](crate::math::vector::Vec2D) positions.
+//!
+//! Positions are $\left[\begin{matrix}x&y\end{matrix}\right]$ with the $x$ axis
+//! pointing right along a row and the $y$ axis pointing down, so that `Vec2D(0,
+//! 0)` is the top-left cell. Any integer component type can be used to address
+//! cells, and positions which lie outside the grid, including those with
+//! negative components, are simply out of bounds.
+//!
+//! Grids can be parsed from text with one character per cell, see
+//! [`Grid2D::parse`].
+//!
+//! For worlds which are unbounded or mostly empty, [`SparseGrid`] only stores
+//! its occupied cells, addressed by two- or three-dimensional positions.
+
+mod grid2d;
+mod parse;
+mod sparse;
+
+pub use grid2d::Grid2D;
+pub use parse::{ParseGridError, ParseGridErrorKind};
+pub use sparse::SparseGrid;
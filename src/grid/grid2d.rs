@@ -0,0 +1,403 @@
+//! Dense two-dimensional grids
+//!
+//! This module provides [`Grid2D`].
+
+use std::{
+    ops::{Index, IndexMut},
+    slice, vec,
+};
+
+use num::{CheckedAdd, CheckedSub, NumCast, One};
+
+use super::{parse, ParseGridError};
+use crate::math::vector::{Stencil, Vec2D};
+
+/// A dense two-dimensional grid of cells of type `T`, addressed by [`Vec2D`]
+/// positions
+///
+/// The cells are stored in a single [`Vec`] in row-major order. A grid
+/// without any cells always has a width and height of zero.
+///
+/// ```
+/// use handyman::{grid::Grid2D, math::vector::Vec2D};
+/// let mut grid = Grid2D::from_fn(3, 2, |Vec2D(x, y)| x + 10 * y);
+/// assert_eq!((grid.width(), grid.height()), (3, 2));
+/// assert_eq!(grid[Vec2D(2, 1)], 12);
+///
+/// grid[Vec2D(0_i64, 1)] = 99;
+/// assert_eq!(grid.get(Vec2D(0_i64, 1)), Some(&99));
+/// assert_eq!(grid.get(Vec2D(-1_i64, 0)), None);
+/// assert_eq!(grid.get(Vec2D(3, 0)), None);
+///
+/// assert_eq!(grid.rows().collect::<Vec<_>>(), [[0, 1, 2], [99, 11, 12]]);
+/// assert_eq!(grid.position(|&cell| cell > 10), Some(Vec2D(0, 1)));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid2D<T> {
+    /// The number of cells in each row, which is zero if there are no cells
+    width: usize,
+    /// The number of rows, which is zero if there are no cells
+    height: usize,
+    /// The cells, in row-major order
+    cells: Vec<T>,
+}
+
+/// The number of cells in a grid of the given size
+///
+/// # Panics
+///
+/// Panics if the number of cells does not fit in a [`usize`].
+const fn area(width: usize, height: usize) -> usize {
+    width
+        .checked_mul(height)
+        .expect("the number of cells should fit in a usize")
+}
+
+impl<T> Grid2D<T> {
+    /// Create a grid from `width * height` cells in row-major order, making
+    /// its size zero if there are no cells
+    const fn from_cells(width: usize, height: usize, cells: Vec<T>) -> Self {
+        let Vec2D(width, height) = if cells.is_empty() {
+            Vec2D(0, 0)
+        } else {
+            Vec2D(width, height)
+        };
+        Self {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Create a [`Grid2D`] by calling a function $f$ with the position of each
+    /// cell, in row-major order
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of cells does not fit in a [`usize`].
+    ///
+    /// ```
+    /// use handyman::grid::Grid2D;
+    /// let grid = Grid2D::from_fn(0, 3, |_| ());
+    /// assert_eq!((grid.width(), grid.height()), (0, 0));
+    /// assert_eq!(grid.rows().count(), 0);
+    /// ```
+    pub fn from_fn<F>(width: usize, height: usize, mut f: F) -> Self
+    where
+        F: FnMut(Vec2D<usize>) -> T,
+    {
+        let mut cells = Vec::with_capacity(area(width, height));
+        cells.extend(
+            (0..height)
+                .flat_map(|y| (0..width).map(move |x| Vec2D(x, y)))
+                .map(&mut f),
+        );
+        Self::from_cells(width, height, cells)
+    }
+
+    /// Create a [`Grid2D`] with rows of `width` cells from a [`Vec`] of cells
+    /// in row-major order
+    ///
+    /// Returns [`None`] if the number of cells is not a multiple of `width`.
+    ///
+    /// ```
+    /// use handyman::{grid::Grid2D, math::vector::Vec2D};
+    /// let grid = Grid2D::from_vec(2, vec![1, 2, 3, 4, 5, 6]).unwrap();
+    /// assert_eq!(grid.height(), 3);
+    /// assert_eq!(grid[Vec2D(1, 2)], 6);
+    /// assert_eq!(Grid2D::from_vec(4, vec![1, 2, 3, 4, 5, 6]), None);
+    /// ```
+    #[must_use]
+    pub fn from_vec(width: usize, cells: Vec<T>) -> Option<Self> {
+        let height = match width {
+            0 if cells.is_empty() => 0,
+            0 => return None,
+            _ => cells.len() / width,
+        };
+        (width.checked_mul(height)? == cells.len()).then(|| Self::from_cells(width, height, cells))
+    }
+
+    /// Parse a grid from text, with one line per row, by calling a function
+    /// $f$ with each character
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ParseGridError`] if $f$ fails for any character, or if the
+    /// rows do not all have the same number of characters.
+    pub fn parse_with<F, E>(text: &str, f: F) -> Result<Self, ParseGridError<E>>
+    where
+        F: FnMut(char) -> Result<T, E>,
+    {
+        parse::parse_rows(text.lines().map(str::chars), f)
+    }
+
+    /// Consume this grid, yielding its cells in row-major order
+    #[must_use]
+    pub fn into_vec(self) -> Vec<T> {
+        self.cells
+    }
+
+    /// The number of cells in each row
+    #[must_use]
+    pub const fn width(&self) -> usize {
+        self.width
+    }
+
+    /// The number of rows
+    #[must_use]
+    pub const fn height(&self) -> usize {
+        self.height
+    }
+
+    /// The size of this grid as a vector
+    /// $\left[\begin{matrix}\text{width}&\text{height}\end{matrix}\right]$
+    #[must_use]
+    pub const fn size(&self) -> Vec2D<usize> {
+        Vec2D(self.width, self.height)
+    }
+
+    /// Find the index into `cells` of the cell at a position, or [`None`] if
+    /// it is out of bounds
+    fn index_of<I: TryInto<usize>>(&self, Vec2D(x, y): Vec2D<I>) -> Option<usize> {
+        let x = x.try_into().ok().filter(|&x| x < self.width)?;
+        let y = y.try_into().ok().filter(|&y| y < self.height)?;
+        Some(y * self.width + x)
+    }
+
+    /// Check whether a position lies within this grid
+    ///
+    /// ```
+    /// use handyman::{grid::Grid2D, math::vector::Vec2D};
+    /// let grid = Grid2D::from_fn(3, 2, |_| ());
+    /// assert!(grid.in_bounds(Vec2D(2, 1)));
+    /// assert!(!grid.in_bounds(Vec2D(2, 2)));
+    /// assert!(!grid.in_bounds(Vec2D(-1, 0)));
+    /// ```
+    pub fn in_bounds<I: TryInto<usize>>(&self, position: Vec2D<I>) -> bool {
+        self.index_of(position).is_some()
+    }
+
+    /// Borrow the cell at a position, or return [`None`] if it is out of
+    /// bounds
+    pub fn get<I: TryInto<usize>>(&self, position: Vec2D<I>) -> Option<&T> {
+        self.index_of(position).map(|index| &self.cells[index])
+    }
+
+    /// Mutably borrow the cell at a position, or return [`None`] if it is out
+    /// of bounds
+    pub fn get_mut<I: TryInto<usize>>(&mut self, position: Vec2D<I>) -> Option<&mut T> {
+        self.index_of(position).map(|index| &mut self.cells[index])
+    }
+
+    /// Iterate over the positions within this grid which are orthogonally
+    /// adjacent to a position, see [`Vec2D::neighbors4`]
+    ///
+    /// ```
+    /// use handyman::{grid::Grid2D, math::vector::Vec2D};
+    /// let grid = Grid2D::new(3, 3, '.');
+    /// assert_eq!(
+    ///     grid.neighbors4(Vec2D(2, 1)).collect::<Vec<_>>(),
+    ///     [Vec2D(2, 0), Vec2D(1, 1), Vec2D(2, 2)]
+    /// );
+    /// assert_eq!(grid.neighbors8(Vec2D(0_i64, 0)).count(), 3);
+    /// ```
+    pub fn neighbors4<'a, I>(&'a self, position: Vec2D<I>) -> impl Iterator<Item = Vec2D<I>> + 'a
+    where
+        I: CheckedAdd + CheckedSub + One + Clone + TryInto<usize> + 'a,
+    {
+        position
+            .neighbors4()
+            .filter(|neighbor| self.in_bounds(neighbor.clone()))
+    }
+
+    /// Iterate over the positions within this grid which are orthogonally or
+    /// diagonally adjacent to a position, see [`Vec2D::neighbors8`]
+    pub fn neighbors8<'a, I>(&'a self, position: Vec2D<I>) -> impl Iterator<Item = Vec2D<I>> + 'a
+    where
+        I: CheckedAdd + CheckedSub + One + Clone + TryInto<usize> + 'a,
+    {
+        position
+            .neighbors8()
+            .filter(|neighbor| self.in_bounds(neighbor.clone()))
+    }
+
+    /// Iterate over the positions within this grid which are neighbours of a
+    /// position according to a [`Stencil`]
+    ///
+    /// ```
+    /// use handyman::{
+    ///     grid::Grid2D,
+    ///     math::vector::{Stencil, Vec2D},
+    /// };
+    /// let board = Grid2D::new(8, 8, ());
+    /// let knight = Stencil::knight();
+    /// assert_eq!(board.neighbors(Vec2D(0_usize, 0), &knight).count(), 2);
+    /// assert_eq!(board.neighbors(Vec2D(7_usize, 4), &knight).count(), 4);
+    /// ```
+    pub fn neighbors<'a, I>(
+        &'a self,
+        position: Vec2D<I>,
+        stencil: &'a Stencil<Vec2D<i64>>,
+    ) -> impl Iterator<Item = Vec2D<I>> + 'a
+    where
+        I: CheckedAdd + CheckedSub + NumCast + Clone + TryInto<usize> + 'a,
+    {
+        stencil
+            .neighbors(position)
+            .filter(|neighbor| self.in_bounds(neighbor.clone()))
+    }
+
+    /// Iterate over the rows of this grid, from top to bottom
+    #[must_use]
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &[T]> + ExactSizeIterator {
+        (0..self.height).map(|y| &self.cells[y * self.width..(y + 1) * self.width])
+    }
+
+    /// Borrow the row at index $y$, or return [`None`] if it is out of bounds
+    #[must_use]
+    pub fn row(&self, y: usize) -> Option<&[T]> {
+        (y < self.height).then(|| &self.cells[y * self.width..(y + 1) * self.width])
+    }
+
+    /// Iterate over the columns of this grid, from left to right, each of
+    /// which is an iterator over its cells from top to bottom
+    ///
+    /// ```
+    /// use handyman::grid::Grid2D;
+    /// let grid = Grid2D::from_vec(2, vec![1, 2, 3, 4]).unwrap();
+    /// let cols: Vec<Vec<_>> = grid.cols().map(|col| col.copied().collect()).collect();
+    /// assert_eq!(cols, [[1, 3], [2, 4]]);
+    /// ```
+    #[must_use]
+    pub fn cols(
+        &self,
+    ) -> impl DoubleEndedIterator<Item = impl Iterator<Item = &T>> + ExactSizeIterator {
+        (0..self.width).map(|x| self.cells[x..].iter().step_by(self.width))
+    }
+
+    /// Iterate over the column at index $x$ from top to bottom, or return
+    /// [`None`] if it is out of bounds
+    #[must_use]
+    pub fn col(&self, x: usize) -> Option<impl Iterator<Item = &T>> {
+        (x < self.width).then(|| self.cells[x..].iter().step_by(self.width))
+    }
+
+    /// Iterate over the cells of this grid in row-major order
+    pub fn iter(&self) -> slice::Iter<'_, T> {
+        self.cells.iter()
+    }
+
+    /// Iterate mutably over the cells of this grid in row-major order
+    pub fn iter_mut(&mut self) -> slice::IterMut<'_, T> {
+        self.cells.iter_mut()
+    }
+
+    /// Iterate over the cells of this grid in row-major order, along with
+    /// their positions
+    ///
+    /// ```
+    /// use handyman::{grid::Grid2D, math::vector::Vec2D};
+    /// let grid = Grid2D::from_vec(2, vec!['a', 'b', 'c', 'd']).unwrap();
+    /// let cells: Vec<_> = grid.iter_with_pos().collect();
+    /// assert_eq!(cells[2], (Vec2D(0, 1), &'c'));
+    /// ```
+    #[must_use]
+    pub fn iter_with_pos(&self) -> impl DoubleEndedIterator<Item = (Vec2D<usize>, &T)> {
+        let width = self.width;
+        self.cells
+            .iter()
+            .enumerate()
+            .map(move |(index, cell)| (Vec2D(index % width, index / width), cell))
+    }
+
+    /// Apply a function $f$ onto every cell of this grid
+    pub fn map<F, U>(self, f: F) -> Grid2D<U>
+    where
+        F: FnMut(T) -> U,
+    {
+        Grid2D {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.into_iter().map(f).collect(),
+        }
+    }
+
+    /// Find the first cell in row-major order matching a predicate, returning
+    /// its position along with the cell
+    pub fn find<P>(&self, mut predicate: P) -> Option<(Vec2D<usize>, &T)>
+    where
+        P: FnMut(&T) -> bool,
+    {
+        self.iter_with_pos().find(|(_, cell)| predicate(cell))
+    }
+
+    /// Find the position of the first cell in row-major order matching a
+    /// predicate
+    pub fn position<P>(&self, predicate: P) -> Option<Vec2D<usize>>
+    where
+        P: FnMut(&T) -> bool,
+    {
+        self.find(predicate).map(|(position, _)| position)
+    }
+}
+
+impl<T: Clone> Grid2D<T> {
+    /// Create a [`Grid2D`] with every cell set to `value`
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of cells does not fit in a [`usize`].
+    #[must_use]
+    pub fn new(width: usize, height: usize, value: T) -> Self {
+        Self::from_cells(width, height, vec![value; area(width, height)])
+    }
+}
+
+impl<T, I: TryInto<usize>> Index<Vec2D<I>> for Grid2D<T> {
+    type Output = T;
+    /// Borrow the cell at a position
+    ///
+    /// # Panics
+    ///
+    /// Panics if the position is out of bounds.
+    fn index(&self, position: Vec2D<I>) -> &Self::Output {
+        self.get(position)
+            .expect("the position should be within the grid")
+    }
+}
+
+impl<T, I: TryInto<usize>> IndexMut<Vec2D<I>> for Grid2D<T> {
+    /// Mutably borrow the cell at a position
+    ///
+    /// # Panics
+    ///
+    /// Panics if the position is out of bounds.
+    fn index_mut(&mut self, position: Vec2D<I>) -> &mut Self::Output {
+        self.get_mut(position)
+            .expect("the position should be within the grid")
+    }
+}
+
+impl<T> IntoIterator for Grid2D<T> {
+    type Item = T;
+    type IntoIter = vec::IntoIter<T>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.cells.into_iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a Grid2D<T> {
+    type Item = &'a T;
+    type IntoIter = slice::Iter<'a, T>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut Grid2D<T> {
+    type Item = &'a mut T;
+    type IntoIter = slice::IterMut<'a, T>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
@@ -0,0 +1,208 @@
+//! Parsing grids from text
+//!
+//! A grid is written as one line of text per row, with one character (or
+//! byte) per cell. Both `\n` and `\r\n` line endings are accepted, and a final
+//! line ending is optional. Every row must have the same number of cells as
+//! the first, otherwise parsing fails with a [`ParseGridError`] giving the line
+//! number of the first row which does not.
+//!
+//! ```
+//! use handyman::{
+//!     grid::{Grid2D, ParseGridErrorKind},
+//!     math::vector::Vec2D,
+//! };
+//! let maze = Grid2D::parse("#####\n#S..#\n#.#E#\n#####\n").unwrap();
+//! assert_eq!(maze.size(), Vec2D(5, 4));
+//! let [start, end] = maze.markers(['S', 'E']);
+//! assert_eq!((start, end), (Some(Vec2D(1, 1)), Some(Vec2D(3, 2))));
+//!
+//! let error = Grid2D::parse("###\n#.\n###").unwrap_err();
+//! assert_eq!(error.line(), 2);
+//! assert_eq!(
+//!     error.kind(),
+//!     &ParseGridErrorKind::RaggedRow {
+//!         expected: 3,
+//!         found: 2
+//!     }
+//! );
+//! assert_eq!(error.to_string(), "line 2 has 2 cells, but the first line has 3");
+//!
+//! let digit = |character: char| character.to_digit(10).ok_or(character);
+//! let heights = Grid2D::parse_with("123\n456", digit).unwrap();
+//! assert_eq!(heights[Vec2D(2, 1)], 6);
+//! let error = Grid2D::parse_with("123\n4x6", digit).unwrap_err();
+//! assert_eq!(
+//!     error.to_string(),
+//!     "could not parse the cell at line 2, column 2: x"
+//! );
+//! ```
+
+use std::{convert::Infallible, error::Error, fmt};
+
+use super::Grid2D;
+use crate::math::vector::Vec2D;
+
+/// The error returned when parsing a grid from text fails
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseGridError<E> {
+    /// The line number, starting from $1$, at which the problem was found
+    line: usize,
+    /// What went wrong
+    kind: ParseGridErrorKind<E>,
+}
+
+/// The ways in which parsing a grid from text can fail, see [`ParseGridError`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseGridErrorKind<E> {
+    /// The row did not have the same number of cells as the first row
+    RaggedRow {
+        /// The number of cells in the first row
+        expected: usize,
+        /// The number of cells in this row
+        found: usize,
+    },
+    /// A cell could not be parsed
+    InvalidCell {
+        /// The column number of the cell, starting from $1$
+        column: usize,
+        /// The error returned when parsing the cell
+        error: E,
+    },
+}
+
+impl<E> ParseGridError<E> {
+    /// The line number, starting from $1$, at which the problem was found
+    #[must_use]
+    pub const fn line(&self) -> usize {
+        self.line
+    }
+
+    /// What went wrong
+    #[must_use]
+    pub const fn kind(&self) -> &ParseGridErrorKind<E> {
+        &self.kind
+    }
+}
+
+impl<E: fmt::Display> fmt::Display for ParseGridError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let line = self.line;
+        match &self.kind {
+            ParseGridErrorKind::RaggedRow { expected, found } => {
+                write!(
+                    f,
+                    "line {line} has {found} cells, but the first line has {expected}"
+                )
+            }
+            ParseGridErrorKind::InvalidCell { column, error } => {
+                write!(
+                    f,
+                    "could not parse the cell at line {line}, column {column}: {error}"
+                )
+            }
+        }
+    }
+}
+
+impl<E: Error + 'static> Error for ParseGridError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match &self.kind {
+            ParseGridErrorKind::InvalidCell { error, .. } => Some(error),
+            ParseGridErrorKind::RaggedRow { .. } => None,
+        }
+    }
+}
+
+/// Parse a grid from its rows, each of which is an iterator over its cells,
+/// with a function $f$ which parses each cell
+pub(super) fn parse_rows<R, C, T, E, F>(rows: R, mut f: F) -> Result<Grid2D<T>, ParseGridError<E>>
+where
+    R: IntoIterator,
+    R::Item: IntoIterator<Item = C>,
+    F: FnMut(C) -> Result<T, E>,
+{
+    let mut width = None;
+    let mut cells = Vec::new();
+    for (index, row) in rows.into_iter().enumerate() {
+        let line = index + 1;
+        let start = cells.len();
+        for (column, cell) in row.into_iter().enumerate() {
+            let cell = f(cell).map_err(|error| ParseGridError {
+                line,
+                kind: ParseGridErrorKind::InvalidCell {
+                    column: column + 1,
+                    error,
+                },
+            })?;
+            cells.push(cell);
+        }
+        let found = cells.len() - start;
+        let expected = *width.get_or_insert(found);
+        if found != expected {
+            return Err(ParseGridError {
+                line,
+                kind: ParseGridErrorKind::RaggedRow { expected, found },
+            });
+        }
+    }
+    Ok(Grid2D::from_vec(width.unwrap_or(0), cells)
+        .unwrap_or_else(|| unreachable!("every row has the same number of cells")))
+}
+
+impl Grid2D<char> {
+    /// Parse a grid of characters from text, with one line per row
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ParseGridError`] if the rows do not all have the same
+    /// number of characters.
+    pub fn parse(text: &str) -> Result<Self, ParseGridError<Infallible>> {
+        Self::parse_with(text, Ok)
+    }
+}
+
+impl Grid2D<u8> {
+    /// Parse a grid of bytes from text, with one line per row
+    ///
+    /// This is faster than [`Grid2D::parse`] for ASCII text.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ParseGridError`] if the rows do not all have the same
+    /// number of bytes.
+    ///
+    /// ```
+    /// use handyman::{grid::Grid2D, math::vector::Vec2D};
+    /// let grid = Grid2D::parse_bytes(b"ab\r\ncd\r\n").unwrap();
+    /// assert_eq!(grid[Vec2D(0, 1)], b'c');
+    /// ```
+    pub fn parse_bytes(text: &[u8]) -> Result<Self, ParseGridError<Infallible>> {
+        let text = text.strip_suffix(b"\n").unwrap_or(text);
+        let rows = text
+            .split(|&byte| byte == b'\n')
+            .map(|line| line.strip_suffix(b"\r").unwrap_or(line).iter().copied());
+        parse_rows(rows, Ok)
+    }
+}
+
+impl<T: PartialEq> Grid2D<T> {
+    /// Find the position of the first cell in row-major order equal to
+    /// `marker`
+    pub fn position_of(&self, marker: &T) -> Option<Vec2D<usize>> {
+        self.position(|cell| cell == marker)
+    }
+
+    /// Iterate over the positions of every cell equal to `marker`, in
+    /// row-major order
+    pub fn positions_of<'a>(&'a self, marker: &'a T) -> impl Iterator<Item = Vec2D<usize>> + 'a {
+        self.iter_with_pos()
+            .filter(move |&(_, cell)| cell == marker)
+            .map(|(position, _)| position)
+    }
+
+    /// Find the positions of the first cells in row-major order equal to each
+    /// of several markers, such as the start and end of a maze
+    pub fn markers<const N: usize>(&self, markers: [T; N]) -> [Option<Vec2D<usize>>; N] {
+        markers.map(|marker| self.position_of(&marker))
+    }
+}
@@ -0,0 +1,359 @@
+//! Sparse grids
+//!
+//! This module provides [`SparseGrid`], a grid which only stores its occupied
+//! cells, for worlds which are unbounded or mostly empty.
+//!
+//! ```
+//! use handyman::{grid::SparseGrid, math::vector::Vec2D};
+//! let mut grid = SparseGrid::<Vec2D<i64>, _>::new();
+//! grid.insert(Vec2D(-2, 0), 'a');
+//! grid.insert(Vec2D(1, -1), 'b');
+//! grid.insert(Vec2D(0, 0), 'c');
+//! assert_eq!(grid.bounds(), Some((Vec2D(-2, -1), Vec2D(1, 0))));
+//! assert_eq!(grid.get(&Vec2D(1, -1)), Some(&'b'));
+//! assert_eq!(
+//!     grid.neighbors8(Vec2D(0, -1)).collect::<Vec<_>>(),
+//!     [(Vec2D(1, -1), &'b'), (Vec2D(0, 0), &'c')]
+//! );
+//! assert_eq!(grid.render(|&cell| cell, '.'), "...b\na.c.");
+//! ```
+
+use std::{
+    collections::{hash_map, HashMap},
+    hash::Hash,
+};
+
+use num::{CheckedAdd, CheckedSub, NumCast, One};
+
+use super::Grid2D;
+use crate::math::vector::{Stencil, Vec2D, Vec3D};
+
+/// A grid which only stores its occupied cells, addressed by positions of
+/// type `V` such as [`Vec2D`] or [`Vec3D`]
+///
+/// The grid keeps track of the bounding box of its positions as cells are
+/// inserted. Removing cells does not shrink the bounding box, see
+/// [`SparseGrid::shrink_bounds`].
+#[derive(Debug, Clone)]
+pub struct SparseGrid<V, T> {
+    /// The occupied cells
+    cells: HashMap<V, T>,
+    /// The inclusive minimum and maximum corners of the bounding box, or
+    /// [`None`] if no cell has been inserted
+    bounds: Option<(V, V)>,
+}
+
+impl<V, T> SparseGrid<V, T> {
+    /// Create an empty [`SparseGrid`]
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            cells: HashMap::new(),
+            bounds: None,
+        }
+    }
+
+    /// The number of occupied cells
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    /// Whether there are no occupied cells
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// Iterate over the occupied cells in an arbitrary order, along with their
+    /// positions
+    pub fn iter(&self) -> hash_map::Iter<'_, V, T> {
+        self.cells.iter()
+    }
+
+    /// Iterate mutably over the occupied cells in an arbitrary order, along
+    /// with their positions
+    pub fn iter_mut(&mut self) -> hash_map::IterMut<'_, V, T> {
+        self.cells.iter_mut()
+    }
+}
+
+impl<V: Clone, T> SparseGrid<V, T> {
+    /// The inclusive minimum and maximum corners of the bounding box of every
+    /// inserted position, or [`None`] if no cell has been inserted
+    #[must_use]
+    pub fn bounds(&self) -> Option<(V, V)> {
+        self.bounds.clone()
+    }
+}
+
+impl<V: Hash + Eq, T> SparseGrid<V, T> {
+    /// Borrow the cell at a position, or return [`None`] if it is empty
+    pub fn get(&self, position: &V) -> Option<&T> {
+        self.cells.get(position)
+    }
+
+    /// Mutably borrow the cell at a position, or return [`None`] if it is
+    /// empty
+    pub fn get_mut(&mut self, position: &V) -> Option<&mut T> {
+        self.cells.get_mut(position)
+    }
+
+    /// Whether the cell at a position is occupied
+    pub fn contains(&self, position: &V) -> bool {
+        self.cells.contains_key(position)
+    }
+
+    /// Empty the cell at a position, returning its value if it was occupied
+    pub fn remove(&mut self, position: &V) -> Option<T> {
+        self.cells.remove(position)
+    }
+}
+
+impl<V: Hash + Eq, T: PartialEq> PartialEq for SparseGrid<V, T> {
+    /// Compare the occupied cells of two grids, ignoring their bounding boxes
+    fn eq(&self, other: &Self) -> bool {
+        self.cells == other.cells
+    }
+}
+
+impl<V: Hash + Eq, T: Eq> Eq for SparseGrid<V, T> {}
+
+impl<V, T> Default for SparseGrid<V, T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<V, T> IntoIterator for SparseGrid<V, T> {
+    type Item = (V, T);
+    type IntoIter = hash_map::IntoIter<V, T>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.cells.into_iter()
+    }
+}
+
+impl<'a, V, T> IntoIterator for &'a SparseGrid<V, T> {
+    type Item = (&'a V, &'a T);
+    type IntoIter = hash_map::Iter<'a, V, T>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, V, T> IntoIterator for &'a mut SparseGrid<V, T> {
+    type Item = (&'a V, &'a mut T);
+    type IntoIter = hash_map::IterMut<'a, V, T>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+/// Implement the functionality of a [`SparseGrid`] which depends on its
+/// position type `$Vec`, whose adjacent neighbours are found with `$near` and
+/// `$far`
+macro_rules! impl_sparse {
+    ($Vec:ident; $near:ident, $far:ident) => {
+        impl<I: Hash + Ord + Clone, T> SparseGrid<$Vec<I>, T> {
+            /// Occupy the cell at a position, returning its previous value if
+            /// it was already occupied
+            pub fn insert(&mut self, position: $Vec<I>, value: T) -> Option<T> {
+                self.bounds = Some(match self.bounds.take() {
+                    None => (position.clone(), position.clone()),
+                    Some((min, max)) => (
+                        min.zip_with(position.clone(), Ord::min),
+                        max.zip_with(position.clone(), Ord::max),
+                    ),
+                });
+                self.cells.insert(position, value)
+            }
+
+            /// Recompute the bounding box from the occupied cells, shrinking it
+            /// after cells have been removed
+            pub fn shrink_bounds(&mut self) {
+                self.bounds = self.cells.keys().cloned().fold(None, |bounds, position| {
+                    Some(match bounds {
+                        None => (position.clone(), position),
+                        Some((min, max)) => (
+                            min.zip_with(position.clone(), Ord::min),
+                            max.zip_with(position, Ord::max),
+                        ),
+                    })
+                });
+            }
+        }
+
+        impl<I: CheckedAdd + CheckedSub + One + Hash + Eq + Clone, T> SparseGrid<$Vec<I>, T> {
+            #[doc = concat!(
+                "Iterate over the occupied cells orthogonally adjacent to a position, along ",
+                "with their positions, see [`", stringify!($Vec), "::", stringify!($near), "`]"
+            )]
+            pub fn $near(&self, position: $Vec<I>) -> impl Iterator<Item = ($Vec<I>, &T)> {
+                position.$near().filter_map(|neighbor| {
+                    let cell = self.cells.get(&neighbor)?;
+                    Some((neighbor, cell))
+                })
+            }
+
+            #[doc = concat!(
+                "Iterate over the occupied cells orthogonally or diagonally adjacent to a ",
+                "position, along with their positions, see [`", stringify!($Vec), "::",
+                stringify!($far), "`]"
+            )]
+            pub fn $far(&self, position: $Vec<I>) -> impl Iterator<Item = ($Vec<I>, &T)> {
+                position.$far().filter_map(|neighbor| {
+                    let cell = self.cells.get(&neighbor)?;
+                    Some((neighbor, cell))
+                })
+            }
+        }
+
+        impl<I: CheckedAdd + CheckedSub + NumCast + Hash + Eq + Clone, T> SparseGrid<$Vec<I>, T> {
+            /// Iterate over the occupied cells which are neighbours of a
+            /// position according to a [`Stencil`], along with their positions
+            pub fn neighbors<'a>(
+                &'a self,
+                position: $Vec<I>,
+                stencil: &'a Stencil<$Vec<i64>>,
+            ) -> impl Iterator<Item = ($Vec<I>, &'a T)> + 'a {
+                stencil.neighbors(position).filter_map(|neighbor| {
+                    let cell = self.cells.get(&neighbor)?;
+                    Some((neighbor, cell))
+                })
+            }
+        }
+
+        impl<I: Hash + Ord + Clone, T> FromIterator<($Vec<I>, T)> for SparseGrid<$Vec<I>, T> {
+            fn from_iter<C: IntoIterator<Item = ($Vec<I>, T)>>(cells: C) -> Self {
+                let mut grid = Self::new();
+                grid.extend(cells);
+                grid
+            }
+        }
+
+        impl<I: Hash + Ord + Clone, T> Extend<($Vec<I>, T)> for SparseGrid<$Vec<I>, T> {
+            fn extend<C: IntoIterator<Item = ($Vec<I>, T)>>(&mut self, cells: C) {
+                for (position, value) in cells {
+                    self.insert(position, value);
+                }
+            }
+        }
+    };
+}
+
+impl_sparse!(Vec2D; neighbors4, neighbors8);
+impl_sparse!(Vec3D; neighbors6, neighbors26);
+
+impl<I, T> SparseGrid<Vec2D<I>, T>
+where
+    I: Hash + Ord + Clone + TryFrom<usize>,
+{
+    /// Create a [`SparseGrid`] from the cells of a [`Grid2D`] for which a
+    /// predicate holds, keeping their positions
+    ///
+    /// # Panics
+    ///
+    /// Panics if a position in the grid cannot be represented by `I`.
+    ///
+    /// ```
+    /// use handyman::{
+    ///     grid::{Grid2D, SparseGrid},
+    ///     math::vector::Vec2D,
+    /// };
+    /// let grid = Grid2D::parse("#..\n..#").unwrap();
+    /// let walls = SparseGrid::<Vec2D<i64>, _>::from_grid(grid, |&cell| cell == '#');
+    /// assert_eq!(walls.len(), 2);
+    /// assert!(walls.contains(&Vec2D(2, 1)));
+    /// ```
+    pub fn from_grid<P>(grid: Grid2D<T>, mut predicate: P) -> Self
+    where
+        P: FnMut(&T) -> bool,
+    {
+        let width = grid.width();
+        grid.into_iter()
+            .enumerate()
+            .filter(|(_, cell)| predicate(cell))
+            .map(|(index, cell)| {
+                let position = Vec2D(index % width, index / width).apply(|component| {
+                    I::try_from(component)
+                        .ok()
+                        .expect("the position should fit in the component type")
+                });
+                (position, cell)
+            })
+            .collect()
+    }
+}
+
+impl<I, T> SparseGrid<Vec2D<I>, T>
+where
+    I: Hash + Eq + Clone + CheckedSub + NumCast,
+{
+    /// Create a [`Grid2D`] covering the bounding box of this grid, with the
+    /// minimum corner of the bounding box at `Vec2D(0, 0)` and every empty
+    /// cell set to `fill`
+    ///
+    /// # Panics
+    ///
+    /// Panics if the size of the bounding box does not fit in a [`usize`].
+    #[must_use]
+    pub fn to_grid(&self, fill: T) -> Grid2D<T>
+    where
+        T: Clone,
+    {
+        let Some((min, max)) = self.bounds.clone() else {
+            return Grid2D::new(0, 0, fill);
+        };
+        // Subtract in I where possible, falling back to i128 when the distance
+        // does not fit in I, such as across the whole range of an i8
+        let offset = |position: Vec2D<I>| {
+            position.zip_with(min.clone(), |component, min| {
+                component
+                    .checked_sub(&min)
+                    .and_then(num::cast)
+                    .or_else(|| {
+                        let distance = num::cast::<I, i128>(component)?
+                            .checked_sub(num::cast::<I, i128>(min)?)?;
+                        usize::try_from(distance).ok()
+                    })
+                    .expect("the bounding box should fit in a usize")
+            })
+        };
+        let Vec2D(width, height) = offset(max)
+            .checked_add(Vec2D(1, 1))
+            .expect("the bounding box should fit in a usize");
+        let mut grid = Grid2D::new(width, height, fill);
+        for (position, cell) in &self.cells {
+            grid[offset(position.clone())] = cell.clone();
+        }
+        grid
+    }
+
+    /// Render the bounding box of this grid as text, with one line per row
+    /// from the minimum $y$ to the maximum, using `glyph` to draw each
+    /// occupied cell and `fill` for each empty cell
+    ///
+    /// # Panics
+    ///
+    /// Panics if the size of the bounding box does not fit in a [`usize`].
+    #[must_use]
+    pub fn render<F>(&self, mut glyph: F, fill: char) -> String
+    where
+        F: FnMut(&T) -> char,
+    {
+        let glyphs = SparseGrid {
+            cells: self
+                .cells
+                .iter()
+                .map(|(position, cell)| (position.clone(), glyph(cell)))
+                .collect(),
+            bounds: self.bounds.clone(),
+        };
+        glyphs
+            .to_grid(fill)
+            .rows()
+            .map(|row| row.iter().collect::<String>())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
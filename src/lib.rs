@@ -1,5 +1,5 @@
 #![doc=include_str!("../README.md")]
-#![feature(non_exhaustive_omitted_patterns_lint)]
+#![feature(array_try_map, non_exhaustive_omitted_patterns_lint)]
 #![warn(
     clippy::cargo,
     clippy::nursery,
@@ -27,7 +27,6 @@
     clippy::same_name_method,
     clippy::semicolon_inside_block,
     clippy::unseparated_literal_suffix,
-    clippy::string_to_string,
     clippy::todo,
     clippy::undocumented_unsafe_blocks,
     clippy::unimplemented,
@@ -46,4 +45,6 @@
     clippy::print_stdout
 )]
 #[allow(clippy::doc_markdown)] // it doesn't like katex
+pub mod grid;
+#[allow(clippy::doc_markdown)] // it doesn't like katex
 pub mod math;
@@ -2,4 +2,10 @@
 //!
 //! This module provides helpful abstractions over mathematical concepts.
 
+pub mod bbox;
+pub mod direction;
+pub mod geometry;
+pub mod hex;
+pub mod matrix;
+pub mod quaternion;
 pub mod vector;
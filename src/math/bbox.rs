@@ -0,0 +1,248 @@
+//! Axis-aligned bounding boxes
+//!
+//! This module provides [`Aabb2`] and [`Aabb3`], boxes whose edges are aligned
+//! with the axes, for tracking the extents of a set of [`Vec2D`] or [`Vec3D`]
+//! points.
+//!
+//! A box is stored as its inclusive minimum and maximum corners, so it always
+//! contains at least one point. Boxes can also be created from and converted
+//! to half-open corners, where the maximum corner lies just outside the box.
+//!
+//! ```
+//! use handyman::math::{bbox::Aabb2, vector::Vec2D};
+//! let points = [Vec2D(3, -1), Vec2D(0, 2), Vec2D(1, 1)];
+//! let bbox = Aabb2::from_points(points).unwrap();
+//! assert_eq!(bbox, Aabb2::new(Vec2D(0, -1), Vec2D(3, 2)).unwrap());
+//! assert_eq!(bbox.end(), Some(Vec2D(4, 3)));
+//! assert_eq!(bbox.area(), Some(16));
+//! assert!(bbox.contains(&Vec2D(3, 2)));
+//! assert_eq!(bbox.clamp(Vec2D(5, -5)), Vec2D(3, -1));
+//!
+//! let other = Aabb2::from_half_open(Vec2D(2, 2), Vec2D(6, 3)).unwrap();
+//! assert_eq!(bbox.intersection(&other), Aabb2::new(Vec2D(2, 2), Vec2D(3, 2)));
+//! assert_eq!(bbox.union(&other), Aabb2::new(Vec2D(0, -1), Vec2D(5, 2)).unwrap());
+//! assert_eq!(other.points().collect::<Vec<_>>(), [
+//!     Vec2D(2, 2),
+//!     Vec2D(3, 2),
+//!     Vec2D(4, 2),
+//!     Vec2D(5, 2)
+//! ]);
+//! ```
+
+use std::ops::Add;
+
+use num::{CheckedAdd, CheckedMul, CheckedSub, One, ToPrimitive};
+
+use super::vector::{Vec2D, Vec3D};
+
+/// Implement the functionality shared by the bounding boxes `$Aabb` over the
+/// vector type `$Vec`, whose number of points is called its `$measure`
+macro_rules! impl_bbox {
+    ($Aabb:ident, $Vec:ident, $measure:ident) => {
+        impl<I> $Aabb<I> {
+            /// The inclusive minimum corner of this box
+            #[must_use]
+            pub const fn min(&self) -> &$Vec<I> {
+                &self.min
+            }
+
+            /// The inclusive maximum corner of this box
+            #[must_use]
+            pub const fn max(&self) -> &$Vec<I> {
+                &self.max
+            }
+        }
+
+        impl<I: Ord + Clone> $Aabb<I> {
+            #[doc = concat!(
+                "Create an [`", stringify!($Aabb), "`] from its inclusive minimum and maximum ",
+                "corners, or return [`None`] if `min` is greater than `max` along any axis"
+            )]
+            #[must_use]
+            pub fn new(min: $Vec<I>, max: $Vec<I>) -> Option<Self> {
+                let ordered = min.iter().zip(max.iter()).all(|(low, high)| low <= high);
+                ordered.then_some(Self { min, max })
+            }
+
+            #[doc = concat!(
+                "Create an [`", stringify!($Aabb), "`] containing a single point"
+            )]
+            #[must_use]
+            pub fn from_point(point: $Vec<I>) -> Self {
+                Self {
+                    min: point.clone(),
+                    max: point,
+                }
+            }
+
+            #[doc = concat!(
+                "Create the smallest [`", stringify!($Aabb), "`] containing every point, or ",
+                "return [`None`] if there are no points"
+            )]
+            pub fn from_points<P>(points: P) -> Option<Self>
+            where
+                P: IntoIterator<Item = $Vec<I>>,
+            {
+                points
+                    .into_iter()
+                    .map(Self::from_point)
+                    .reduce(|bbox, point| bbox.union(&point))
+            }
+
+            /// Whether a point lies inside this box
+            #[must_use]
+            pub fn contains(&self, point: &$Vec<I>) -> bool {
+                self.min.iter().zip(point.iter()).all(|(min, value)| min <= value)
+                    && point.iter().zip(self.max.iter()).all(|(value, max)| value <= max)
+            }
+
+            /// The box of the points inside both boxes, or [`None`] if they do
+            /// not overlap
+            #[must_use]
+            pub fn intersection(&self, other: &Self) -> Option<Self> {
+                Self::new(
+                    self.min.clone().zip_with(other.min.clone(), Ord::max),
+                    self.max.clone().zip_with(other.max.clone(), Ord::min),
+                )
+            }
+
+            /// The smallest box containing both boxes
+            #[must_use]
+            pub fn union(&self, other: &Self) -> Self {
+                Self {
+                    min: self.min.clone().zip_with(other.min.clone(), Ord::min),
+                    max: self.max.clone().zip_with(other.max.clone(), Ord::max),
+                }
+            }
+
+            /// The point inside this box which is closest to a point, moving
+            /// it along each axis onto the box if it lies outside
+            #[must_use]
+            pub fn clamp(&self, point: $Vec<I>) -> $Vec<I> {
+                point
+                    .zip_with(self.min.clone(), Ord::max)
+                    .zip_with(self.max.clone(), Ord::min)
+            }
+        }
+
+        impl<I: Ord + Clone + CheckedSub + One> $Aabb<I> {
+            #[doc = concat!(
+                "Create an [`", stringify!($Aabb), "`] from its inclusive minimum corner and ",
+                "exclusive maximum corner, or return [`None`] if the box would be empty"
+            )]
+            #[must_use]
+            pub fn from_half_open(start: $Vec<I>, end: $Vec<I>) -> Option<Self> {
+                if start.iter().zip(end.iter()).any(|(start, end)| start >= end) {
+                    return None;
+                }
+                let max = end.checked_sub($Vec::from_fn(|_| I::one()))?;
+                Self::new(start, max)
+            }
+        }
+
+        impl<I: Ord + Clone + CheckedAdd + CheckedSub> $Aabb<I> {
+            /// Grow this box by a margin on every side, or shrink it if the
+            /// margin is negative
+            ///
+            /// Returns [`None`] if a corner overflows, or if the box would be
+            /// empty.
+            #[must_use]
+            pub fn expand(&self, margin: I) -> Option<Self> {
+                let margin = $Vec::from_fn(|_| margin.clone());
+                Self::new(
+                    self.min.clone().checked_sub(margin.clone())?,
+                    self.max.clone().checked_add(margin)?,
+                )
+            }
+        }
+
+        impl<I: Clone + CheckedAdd + One> $Aabb<I> {
+            /// The exclusive maximum corner of this box, which lies just
+            /// outside it, or [`None`] if it overflows
+            #[must_use]
+            pub fn end(&self) -> Option<$Vec<I>> {
+                self.max.clone().checked_add($Vec::from_fn(|_| I::one()))
+            }
+        }
+
+        impl<I: Clone + CheckedAdd + CheckedSub + One> $Aabb<I> {
+            /// The number of points along each axis of this box, or [`None`] if
+            /// it overflows
+            #[must_use]
+            pub fn size(&self) -> Option<$Vec<I>> {
+                self.max
+                    .clone()
+                    .checked_sub(self.min.clone())?
+                    .checked_add($Vec::from_fn(|_| I::one()))
+            }
+        }
+
+        impl<I: Clone + CheckedAdd + CheckedSub + CheckedMul + One> $Aabb<I> {
+            /// The number of points inside this box, or [`None`] if it
+            /// overflows
+            #[must_use]
+            pub fn $measure(&self) -> Option<I> {
+                self.size()?
+                    .into_iter()
+                    .try_fold(I::one(), |product, length| product.checked_mul(&length))
+            }
+        }
+
+        impl<I> From<$Aabb<I>> for ($Vec<I>, $Vec<I>) {
+            fn from(bbox: $Aabb<I>) -> Self {
+                (bbox.min, bbox.max)
+            }
+        }
+    };
+}
+
+/// A two-dimensional axis-aligned bounding box, containing every point
+/// between its inclusive minimum and maximum corners
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Aabb2<I> {
+    /// The inclusive minimum corner, which is at most `max` along each axis
+    min: Vec2D<I>,
+    /// The inclusive maximum corner, which is at least `min` along each axis
+    max: Vec2D<I>,
+}
+
+/// A three-dimensional axis-aligned bounding box, containing every point
+/// between its inclusive minimum and maximum corners
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Aabb3<I> {
+    /// The inclusive minimum corner, which is at most `max` along each axis
+    min: Vec3D<I>,
+    /// The inclusive maximum corner, which is at least `min` along each axis
+    max: Vec3D<I>,
+}
+
+impl_bbox!(Aabb2, Vec2D, area);
+impl_bbox!(Aabb3, Vec3D, volume);
+
+impl<I: Add<Output = I> + PartialOrd + One + ToPrimitive + Clone> Aabb2<I> {
+    /// Iterate over the lattice points inside this box in row-major order,
+    /// i.e. by increasing $x$ within each row of increasing $y$
+    pub fn points(&self) -> impl Iterator<Item = Vec2D<I>> {
+        let Self { min, max } = self.clone();
+        num::range_inclusive(min.1, max.1).flat_map(move |y| {
+            num::range_inclusive(min.0.clone(), max.0.clone()).map(move |x| Vec2D(x, y.clone()))
+        })
+    }
+}
+
+impl<I: Add<Output = I> + PartialOrd + One + ToPrimitive + Clone> Aabb3<I> {
+    /// Iterate over the lattice points inside this box in row-major order,
+    /// i.e. by increasing $x$, then $y$, then $z$
+    pub fn points(&self) -> impl Iterator<Item = Vec3D<I>> {
+        let Self { min, max } = self.clone();
+        num::range_inclusive(min.2.clone(), max.2.clone()).flat_map(move |z| {
+            let Vec3D(min_x, min_y, _) = min.clone();
+            let Vec3D(max_x, max_y, _) = max.clone();
+            num::range_inclusive(min_y, max_y).flat_map(move |y| {
+                let z = z.clone();
+                num::range_inclusive(min_x.clone(), max_x.clone())
+                    .map(move |x| Vec3D(x, y.clone(), z.clone()))
+            })
+        })
+    }
+}
@@ -0,0 +1,387 @@
+//! Compass directions
+//!
+//! This module provides [`Direction4`], the four cardinal directions, and
+//! [`Direction8`], which adds the four diagonal directions.
+//!
+//! A direction can be converted to the unit [`Vec2D`] pointing that way. As
+//! the $y$ axis points up in mathematics but down in grids and on screens, the
+//! convention is given by a [`YAxis`]. Conversions which take no [`YAxis`],
+//! including [`From`]/[`Into`], [`TryFrom`] and moving a position with
+//! `position + direction`, use [`YAxis::Down`] to match
+//! [`Grid2D`](crate::grid::Grid2D), so that north is towards the first row.
+//! Moving with `+` needs signed components, so for unsigned positions such as
+//! those of a [`Grid2D`](crate::grid::Grid2D), use [`Vec2D::checked_add_dir`].
+//!
+//! Directions can be parsed from the characters `^>v<`, `NESW` and `UDLR` (in
+//! either case), with [`TryFrom<char>`] or [`FromStr`]. A [`Direction8`] can
+//! also be parsed from the strings `NE`, `SE`, `SW` and `NW`.
+//!
+//! ```
+//! use handyman::math::{
+//!     direction::{Direction4, Direction8, YAxis},
+//!     vector::Vec2D,
+//! };
+//! let mut position = Vec2D(2, 2);
+//! for step in "^^>v".chars() {
+//!     position += Direction4::try_from(step).unwrap();
+//! }
+//! assert_eq!(position, Vec2D(3, 1));
+//!
+//! assert_eq!(Direction4::North.turn_right(), Direction4::East);
+//! assert_eq!(Direction4::North.to_vec2d::<i32>(YAxis::Up), Vec2D(0, 1));
+//! assert_eq!(Vec2D(0, 1) + Direction4::North, Vec2D(0, 0));
+//! assert_eq!(Vec2D(0_usize, 1).checked_add_dir(Direction4::North), Some(Vec2D(0, 0)));
+//! assert_eq!(Vec2D(0_usize, 0).checked_add_dir(Direction8::NorthEast), None);
+//! assert_eq!(Direction4::try_from(Vec2D(-1, 0)), Ok(Direction4::West));
+//! assert!(Direction4::try_from(Vec2D(1, 1)).is_err());
+//!
+//! assert_eq!("NE".parse(), Ok(Direction8::NorthEast));
+//! assert_eq!(Direction8::NorthEast.turn_left(), Direction8::North);
+//! assert_eq!(Direction8::all().len(), 8);
+//! ```
+
+use std::{
+    error::Error,
+    fmt,
+    ops::{Add, AddAssign, Sub, SubAssign},
+    str::FromStr,
+};
+
+use num::Signed;
+
+use super::vector::Vec2D;
+
+/// The direction in which the $y$ axis points, which decides whether north is
+/// towards positive or negative $y$
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum YAxis {
+    /// The $y$ axis points up, as usual in mathematics, so north is
+    /// $\left[\begin{matrix}0&1\end{matrix}\right]$
+    Up,
+    /// The $y$ axis points down, as usual for grids and screens, so north is
+    /// $\left[\begin{matrix}0&-1\end{matrix}\right]$
+    Down,
+}
+
+/// One of the four cardinal directions
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction4 {
+    /// North, up, or `^`
+    North,
+    /// East, right, or `>`
+    East,
+    /// South, down, or `v`
+    South,
+    /// West, left, or `<`
+    West,
+}
+
+/// One of the four cardinal or four diagonal directions
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction8 {
+    /// North, up, or `^`
+    North,
+    /// North-east
+    NorthEast,
+    /// East, right, or `>`
+    East,
+    /// South-east
+    SouthEast,
+    /// South, down, or `v`
+    South,
+    /// South-west
+    SouthWest,
+    /// West, left, or `<`
+    West,
+    /// North-west
+    NorthWest,
+}
+
+/// The error returned when a character, string or vector does not describe a
+/// direction
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InvalidDirectionError(());
+
+impl fmt::Display for InvalidDirectionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("invalid direction")
+    }
+}
+
+impl Error for InvalidDirectionError {}
+
+/// Convert a unit offset of $-1$, $0$ or $1$ into any signed type
+fn unit<I: Signed>(delta: i8) -> I {
+    match delta {
+        -1 => -I::one(),
+        0 => I::zero(),
+        _ => I::one(),
+    }
+}
+
+/// Parse one of the characters `^>v<`, `NESW` or `UDLR` (in either case) as a
+/// cardinal direction
+const fn parse_cardinal(character: char) -> Option<Direction4> {
+    match character {
+        '^' | 'N' | 'n' | 'U' | 'u' => Some(Direction4::North),
+        '>' | 'E' | 'e' | 'R' | 'r' => Some(Direction4::East),
+        'v' | 'S' | 's' | 'D' | 'd' => Some(Direction4::South),
+        '<' | 'W' | 'w' | 'L' | 'l' => Some(Direction4::West),
+        _ => None,
+    }
+}
+
+impl Direction4 {
+    /// Every direction, clockwise from north
+    const ALL: [Self; 4] = [Self::North, Self::East, Self::South, Self::West];
+
+    /// The offset to the adjacent point in this direction with the $y$ axis
+    /// pointing down
+    const fn offset(self) -> (i8, i8) {
+        match self {
+            Self::North => (0, -1),
+            Self::East => (1, 0),
+            Self::South => (0, 1),
+            Self::West => (-1, 0),
+        }
+    }
+
+    /// The index of this direction in [`Direction4::ALL`]
+    const fn index(self) -> usize {
+        match self {
+            Self::North => 0,
+            Self::East => 1,
+            Self::South => 2,
+            Self::West => 3,
+        }
+    }
+
+    /// The arrow character `^`, `>`, `v` or `<` pointing in this direction
+    #[must_use]
+    pub const fn arrow(self) -> char {
+        match self {
+            Self::North => '^',
+            Self::East => '>',
+            Self::South => 'v',
+            Self::West => '<',
+        }
+    }
+}
+
+impl Direction8 {
+    /// Every direction, clockwise from north
+    const ALL: [Self; 8] = [
+        Self::North,
+        Self::NorthEast,
+        Self::East,
+        Self::SouthEast,
+        Self::South,
+        Self::SouthWest,
+        Self::West,
+        Self::NorthWest,
+    ];
+
+    /// The offset to the adjacent point in this direction with the $y$ axis
+    /// pointing down
+    pub(crate) const fn offset(self) -> (i8, i8) {
+        match self {
+            Self::North => (0, -1),
+            Self::NorthEast => (1, -1),
+            Self::East => (1, 0),
+            Self::SouthEast => (1, 1),
+            Self::South => (0, 1),
+            Self::SouthWest => (-1, 1),
+            Self::West => (-1, 0),
+            Self::NorthWest => (-1, -1),
+        }
+    }
+
+    /// The index of this direction in [`Direction8::ALL`]
+    const fn index(self) -> usize {
+        match self {
+            Self::North => 0,
+            Self::NorthEast => 1,
+            Self::East => 2,
+            Self::SouthEast => 3,
+            Self::South => 4,
+            Self::SouthWest => 5,
+            Self::West => 6,
+            Self::NorthWest => 7,
+        }
+    }
+
+    /// Whether this is one of the four diagonal directions
+    #[must_use]
+    pub const fn is_diagonal(self) -> bool {
+        self.index() % 2 == 1
+    }
+}
+
+impl From<Direction4> for Direction8 {
+    fn from(direction: Direction4) -> Self {
+        match direction {
+            Direction4::North => Self::North,
+            Direction4::East => Self::East,
+            Direction4::South => Self::South,
+            Direction4::West => Self::West,
+        }
+    }
+}
+
+impl TryFrom<Direction8> for Direction4 {
+    type Error = InvalidDirectionError;
+    /// Convert a cardinal [`Direction8`] into a [`Direction4`], failing for
+    /// diagonal directions
+    fn try_from(direction: Direction8) -> Result<Self, Self::Error> {
+        Self::ALL
+            .into_iter()
+            .find(|&cardinal| Direction8::from(cardinal) == direction)
+            .ok_or(InvalidDirectionError(()))
+    }
+}
+
+impl FromStr for Direction8 {
+    type Err = InvalidDirectionError;
+    /// Parse a direction from one of the characters `^>v<`, `NESW` or `UDLR`,
+    /// or one of the strings `NE`, `SE`, `SW` or `NW` (in either case)
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let diagonal = [
+            ("NE", Self::NorthEast),
+            ("SE", Self::SouthEast),
+            ("SW", Self::SouthWest),
+            ("NW", Self::NorthWest),
+        ]
+        .into_iter()
+        .find(|(name, _)| text.eq_ignore_ascii_case(name));
+        match diagonal {
+            Some((_, direction)) => Ok(direction),
+            None => text.parse::<Direction4>().map(Self::from),
+        }
+    }
+}
+
+/// Implement the functionality shared by the direction type `$Dir` with `$n`
+/// directions, each `$turn` apart
+macro_rules! impl_direction {
+    ($Dir:ident, $n:literal, $turn:literal) => {
+        impl $Dir {
+            /// Every direction, clockwise from north
+            #[must_use]
+            pub const fn all() -> [Self; $n] {
+                Self::ALL
+            }
+
+            #[doc = concat!("Turn counter-clockwise by ", $turn)]
+            #[must_use]
+            pub const fn turn_left(self) -> Self {
+                Self::ALL[(self.index() + $n - 1) % $n]
+            }
+
+            #[doc = concat!("Turn clockwise by ", $turn)]
+            #[must_use]
+            pub const fn turn_right(self) -> Self {
+                Self::ALL[(self.index() + 1) % $n]
+            }
+
+            /// Turn around to face the opposite direction
+            #[must_use]
+            pub const fn reverse(self) -> Self {
+                Self::ALL[(self.index() + $n / 2) % $n]
+            }
+
+            /// The vector to the adjacent point in this direction, with the
+            /// $y$ axis pointing as given
+            #[must_use]
+            pub fn to_vec2d<I: Signed>(self, y_axis: YAxis) -> Vec2D<I> {
+                let (dx, dy) = self.offset();
+                let vector = Vec2D(unit(dx), unit(dy));
+                match y_axis {
+                    YAxis::Up => vector.flip_y(),
+                    YAxis::Down => vector,
+                }
+            }
+
+            /// Find the direction whose [`to_vec2d`](Self::to_vec2d) is a
+            /// vector, with the $y$ axis pointing as given
+            pub fn from_vec2d<I: Signed>(vector: &Vec2D<I>, y_axis: YAxis) -> Option<Self> {
+                Self::ALL
+                    .into_iter()
+                    .find(|direction| direction.to_vec2d::<I>(y_axis) == *vector)
+            }
+        }
+
+        impl<I: Signed> From<$Dir> for Vec2D<I> {
+            /// The vector to the adjacent point in a direction, with the $y$
+            /// axis pointing down
+            fn from(direction: $Dir) -> Self {
+                direction.to_vec2d(YAxis::Down)
+            }
+        }
+
+        impl<I: Signed> TryFrom<Vec2D<I>> for $Dir {
+            type Error = InvalidDirectionError;
+            /// Find the direction whose vector is this one, with the $y$ axis
+            /// pointing down
+            fn try_from(vector: Vec2D<I>) -> Result<Self, Self::Error> {
+                Self::from_vec2d(&vector, YAxis::Down).ok_or(InvalidDirectionError(()))
+            }
+        }
+
+        impl TryFrom<char> for $Dir {
+            type Error = InvalidDirectionError;
+            /// Parse a cardinal direction from one of the characters `^>v<`,
+            /// `NESW` or `UDLR` (in either case)
+            fn try_from(character: char) -> Result<Self, Self::Error> {
+                parse_cardinal(character)
+                    .map(Self::from)
+                    .ok_or(InvalidDirectionError(()))
+            }
+        }
+
+        impl<I: Signed> Add<$Dir> for Vec2D<I> {
+            type Output = Self;
+            /// Move to the adjacent point in a direction, with the $y$ axis
+            /// pointing down
+            fn add(self, rhs: $Dir) -> Self::Output {
+                self + Self::from(rhs)
+            }
+        }
+
+        impl<I: Signed> Sub<$Dir> for Vec2D<I> {
+            type Output = Self;
+            /// Move to the adjacent point opposite a direction, with the $y$
+            /// axis pointing down
+            fn sub(self, rhs: $Dir) -> Self::Output {
+                self - Self::from(rhs)
+            }
+        }
+
+        impl<I: Signed + AddAssign> AddAssign<$Dir> for Vec2D<I> {
+            fn add_assign(&mut self, rhs: $Dir) {
+                *self += Self::from(rhs);
+            }
+        }
+
+        impl<I: Signed + SubAssign> SubAssign<$Dir> for Vec2D<I> {
+            fn sub_assign(&mut self, rhs: $Dir) {
+                *self -= Self::from(rhs);
+            }
+        }
+    };
+}
+
+impl_direction!(Direction4, 4, "a quarter turn");
+impl_direction!(Direction8, 8, "an eighth of a turn");
+
+impl FromStr for Direction4 {
+    type Err = InvalidDirectionError;
+    /// Parse a direction from one of the characters `^>v<`, `NESW` or `UDLR`
+    /// (in either case)
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let mut characters = text.chars();
+        match (characters.next(), characters.next()) {
+            (Some(character), None) => Self::try_from(character),
+            _ => Err(InvalidDirectionError(())),
+        }
+    }
+}
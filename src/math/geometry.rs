@@ -0,0 +1,547 @@
+//! Exact plane geometry
+//!
+//! This module provides [`Segment2`], [`Ray2`] and [`Line2`], line segments,
+//! half-lines and infinite lines through points with integer coordinates,
+//! along with exact predicates on them. Everything is computed with integer
+//! arithmetic, and intersection points have [`Ratio`] coordinates, so the
+//! results are never affected by rounding.
+//!
+//! The predicates multiply pairs of differences between coordinates, and add
+//! or subtract two such products, which must not overflow the coordinate type.
+//! Building an intersection point also multiplies its start coordinates, and
+//! the offsets to it from there, by the denominator of the reduced result,
+//! which is at most such a sum of products. This grows with the cube of the
+//! coordinates: all coordinates within $\pm 2^8$ are always safe for `i32`,
+//! and within $\pm 2^{19}$ for `i64`.
+//!
+//! An [`Orientation`] is given with the $y$ axis pointing up. On a grid with
+//! the $y$ axis pointing down, such as a [`Grid2D`](crate::grid::Grid2D),
+//! clockwise and counter-clockwise turns appear the other way around.
+//!
+//! ```
+//! use handyman::math::{
+//!     geometry::{
+//!         orientation, Line2, LineIntersection, Orientation, Ray2, Segment2,
+//!         SegmentIntersection,
+//!     },
+//!     vector::Vec2D,
+//! };
+//! use num::rational::Ratio;
+//! assert_eq!(
+//!     orientation(Vec2D(0, 0), Vec2D(2, 0), Vec2D(1, 1)),
+//!     Orientation::CounterClockwise
+//! );
+//!
+//! let segment = Segment2::new(Vec2D(0, 0), Vec2D(4, 2));
+//! let crossing = Segment2::new(Vec2D(0, 2), Vec2D(2, 0));
+//! assert!(segment.intersects(&crossing));
+//! assert_eq!(
+//!     segment.intersection(&crossing),
+//!     Some(SegmentIntersection::Point(Vec2D(Ratio::new(4, 3), Ratio::new(2, 3))))
+//! );
+//!
+//! let collinear = Segment2::new(Vec2D(6, 3), Vec2D(2, 1));
+//! assert!(segment.overlaps(&collinear));
+//! assert_eq!(
+//!     segment.intersection(&collinear),
+//!     Some(SegmentIntersection::Overlap(Segment2::new(Vec2D(2, 1), Vec2D(4, 2))))
+//! );
+//! assert!(!segment.intersects(&Segment2::new(Vec2D(0, 1), Vec2D(4, 3))));
+//!
+//! let ray = Ray2::new(Vec2D(0, 1), Vec2D(1, 1)).unwrap();
+//! assert!(ray.intersects(&segment));
+//! assert_eq!(
+//!     ray.intersection(&segment),
+//!     Some(SegmentIntersection::Point(Vec2D(Ratio::from_integer(2), Ratio::from_integer(1))))
+//! );
+//! assert!(!ray.intersects(&Segment2::new(Vec2D(-1, 0), Vec2D(-1, 2))));
+//!
+//! let line = Line2::new(Vec2D(0, 0), Vec2D(4, 2)).unwrap();
+//! assert!(line.contains(&Vec2D(-2, -1)));
+//! assert_eq!(
+//!     line.intersection(&segment.line().unwrap()),
+//!     Some(LineIntersection::Coincident)
+//! );
+//! ```
+
+use std::cmp::Ordering;
+
+use num::{rational::Ratio, Integer, Signed};
+
+use super::vector::Vec2D;
+
+/// The direction of the turn made when travelling through three points in
+/// order, with the $y$ axis pointing up
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Orientation {
+    /// The path turns right
+    Clockwise,
+    /// The points lie on a single line
+    Collinear,
+    /// The path turns left
+    CounterClockwise,
+}
+
+impl Orientation {
+    /// The orientation of two vectors from their cross product
+    fn from_cross<I: Signed>(cross: &I) -> Self {
+        if cross.is_positive() {
+            Self::CounterClockwise
+        } else if cross.is_negative() {
+            Self::Clockwise
+        } else {
+            Self::Collinear
+        }
+    }
+}
+
+/// Find the direction of the turn made when travelling from `first` to
+/// `second` to `third`, with the $y$ axis pointing up
+#[must_use]
+pub fn orientation<I: Signed + Clone>(
+    first: Vec2D<I>,
+    second: Vec2D<I>,
+    third: Vec2D<I>,
+) -> Orientation {
+    Orientation::from_cross(&(second - first.clone()).perp_dot(third - first))
+}
+
+/// The point a fraction `numerator / denominator` of the way along
+/// `direction` from `start`
+///
+/// The fraction is reduced against each component of `direction` before
+/// multiplying, so the products only involve the reduced denominator of the
+/// result.
+fn point_along<I: Integer + Clone>(
+    start: Vec2D<I>,
+    direction: Vec2D<I>,
+    numerator: &I,
+    denominator: &I,
+) -> Vec2D<Ratio<I>> {
+    start.zip_with(direction, |start, direction| {
+        Ratio::new(numerator.clone(), denominator.clone()) * direction + start
+    })
+}
+
+/// The line segment between two points, including both
+///
+/// The endpoints may be equal, in which case the segment is a single point.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Segment2<I> {
+    /// The first endpoint
+    pub start: Vec2D<I>,
+    /// The second endpoint
+    pub end: Vec2D<I>,
+}
+
+/// The points shared by two segments, or by a ray and a segment, see
+/// [`Segment2::intersection`] and [`Ray2::intersection`]
+#[derive(Debug, Clone)]
+pub enum SegmentIntersection<I> {
+    /// The segments meet at a single point
+    Point(Vec2D<Ratio<I>>),
+    /// The segments are collinear and share every point of a segment, which
+    /// runs in the direction of the first segment or of the ray
+    Overlap(Segment2<I>),
+}
+
+impl<I: Integer + Clone> PartialEq for SegmentIntersection<I> {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Point(point), Self::Point(other)) => point == other,
+            (Self::Overlap(segment), Self::Overlap(other)) => segment == other,
+            (Self::Point(_), Self::Overlap(_)) | (Self::Overlap(_), Self::Point(_)) => false,
+        }
+    }
+}
+
+impl<I: Integer + Clone> Eq for SegmentIntersection<I> {}
+
+impl<I> Segment2<I> {
+    /// Create a [`Segment2`] between two points
+    #[must_use]
+    pub const fn new(start: Vec2D<I>, end: Vec2D<I>) -> Self {
+        Self { start, end }
+    }
+}
+
+impl<I: Integer + Signed + Clone> Segment2<I> {
+    /// The infinite line through this segment, or [`None`] if its endpoints
+    /// are equal
+    #[must_use]
+    pub fn line(&self) -> Option<Line2<I>> {
+        Line2::new(self.start.clone(), self.end.clone())
+    }
+
+    /// Find the direction of the turn made when travelling along this segment
+    /// and then to a point, see [`orientation`]
+    #[must_use]
+    pub fn orientation(&self, point: &Vec2D<I>) -> Orientation {
+        orientation(self.start.clone(), self.end.clone(), point.clone())
+    }
+
+    /// Whether a point lies on this segment
+    #[must_use]
+    pub fn contains(&self, point: &Vec2D<I>) -> bool {
+        let between =
+            |start: &I, end: &I, value: &I| start.min(end) <= value && value <= start.max(end);
+        self.orientation(point) == Orientation::Collinear
+            && between(&self.start.0, &self.end.0, &point.0)
+            && between(&self.start.1, &self.end.1, &point.1)
+    }
+
+    /// Whether this segment shares any point with another
+    #[must_use]
+    pub fn intersects(&self, other: &Self) -> bool {
+        self.intersection(other).is_some()
+    }
+
+    /// Whether this segment and another are collinear and share more than a
+    /// single point
+    #[must_use]
+    pub fn overlaps(&self, other: &Self) -> bool {
+        matches!(
+            self.intersection(other),
+            Some(SegmentIntersection::Overlap(_))
+        )
+    }
+
+    /// Find the points shared by this segment and another, or return [`None`]
+    /// if there are none
+    #[must_use]
+    pub fn intersection(&self, other: &Self) -> Option<SegmentIntersection<I>> {
+        let direction = self.end.clone() - self.start.clone();
+        let other_direction = other.end.clone() - other.start.clone();
+        let denominator = direction.clone().perp_dot(other_direction.clone());
+        if denominator.is_zero() {
+            return self.parallel_intersection(other);
+        }
+        // The intersection of the lines through the segments is at
+        // `along / denominator` of the way along this segment, and at
+        // `other_along / denominator` of the way along the other
+        let offset = other.start.clone() - self.start.clone();
+        let mut along = offset.clone().perp_dot(other_direction);
+        let mut other_along = offset.perp_dot(direction.clone());
+        let mut denominator = denominator;
+        if denominator.is_negative() {
+            along = -along;
+            other_along = -other_along;
+            denominator = -denominator;
+        }
+        let within = |value: &I| !value.is_negative() && value <= &denominator;
+        if !within(&along) || !within(&other_along) {
+            return None;
+        }
+        Some(SegmentIntersection::Point(point_along(
+            self.start.clone(),
+            direction,
+            &along,
+            &denominator,
+        )))
+    }
+
+    /// Find the points shared by this segment and another which is parallel
+    /// to it, including when either is a single point
+    fn parallel_intersection(&self, other: &Self) -> Option<SegmentIntersection<I>> {
+        let single =
+            |point: &Vec2D<I>| SegmentIntersection::Point(point.clone().apply(Ratio::from_integer));
+        if self.start == self.end {
+            return other.contains(&self.start).then(|| single(&self.start));
+        }
+        if other.start == other.end {
+            return self.contains(&other.start).then(|| single(&other.start));
+        }
+        if self.orientation(&other.start) != Orientation::Collinear {
+            return None;
+        }
+        // Order the points by their distance along this segment
+        let direction = self.end.clone() - self.start.clone();
+        let key = |point: &Vec2D<I>| (point.clone() - self.start.clone()).dot(direction.clone());
+        let (low, high) = if key(&other.start) <= key(&other.end) {
+            (&other.start, &other.end)
+        } else {
+            (&other.end, &other.start)
+        };
+        let start = if key(low) > key(&self.start) {
+            low
+        } else {
+            &self.start
+        };
+        let end = if key(high) < key(&self.end) {
+            high
+        } else {
+            &self.end
+        };
+        match key(start).cmp(&key(end)) {
+            Ordering::Less => Some(SegmentIntersection::Overlap(Self::new(
+                start.clone(),
+                end.clone(),
+            ))),
+            Ordering::Equal => Some(single(start)),
+            Ordering::Greater => None,
+        }
+    }
+}
+
+/// An infinite line through two distinct points
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Line2<I> {
+    /// A point on the line
+    point: Vec2D<I>,
+    /// The non-zero difference between two points on the line
+    direction: Vec2D<I>,
+}
+
+/// The points shared by two lines, see [`Line2::intersection`]
+#[derive(Debug, Clone)]
+pub enum LineIntersection<I> {
+    /// The lines cross at a single point
+    Point(Vec2D<Ratio<I>>),
+    /// The lines are the same, and share every point
+    Coincident,
+}
+
+impl<I: Integer + Clone> PartialEq for LineIntersection<I> {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Point(point), Self::Point(other)) => point == other,
+            (Self::Coincident, Self::Coincident) => true,
+            (Self::Point(_), Self::Coincident) | (Self::Coincident, Self::Point(_)) => false,
+        }
+    }
+}
+
+impl<I: Integer + Clone> Eq for LineIntersection<I> {}
+
+impl<I> Line2<I> {
+    /// The first point this line was created through
+    #[must_use]
+    pub const fn point(&self) -> &Vec2D<I> {
+        &self.point
+    }
+
+    /// The difference from the first point this line was created through to
+    /// the second, which is never zero
+    #[must_use]
+    pub const fn direction(&self) -> &Vec2D<I> {
+        &self.direction
+    }
+}
+
+impl<I: Integer + Signed + Clone> Line2<I> {
+    /// Create the [`Line2`] through two points, or return [`None`] if they are
+    /// equal
+    #[must_use]
+    pub fn new(first: Vec2D<I>, second: Vec2D<I>) -> Option<Self> {
+        if first == second {
+            return None;
+        }
+        Some(Self {
+            direction: second - first.clone(),
+            point: first,
+        })
+    }
+
+    /// Find which side of this line a point lies on, as the direction of the
+    /// turn made when travelling along the line and then to the point
+    #[must_use]
+    pub fn orientation(&self, point: &Vec2D<I>) -> Orientation {
+        Orientation::from_cross(
+            &self
+                .direction
+                .clone()
+                .perp_dot(point.clone() - self.point.clone()),
+        )
+    }
+
+    /// Whether a point lies on this line
+    #[must_use]
+    pub fn contains(&self, point: &Vec2D<I>) -> bool {
+        self.orientation(point) == Orientation::Collinear
+    }
+
+    /// Whether this line is parallel to another, including when they are the
+    /// same line
+    #[must_use]
+    pub fn is_parallel(&self, other: &Self) -> bool {
+        self.direction
+            .clone()
+            .perp_dot(other.direction.clone())
+            .is_zero()
+    }
+
+    /// Whether this line shares any point with another, which is the case
+    /// unless they are distinct parallel lines
+    #[must_use]
+    pub fn intersects(&self, other: &Self) -> bool {
+        !self.is_parallel(other) || self.contains(&other.point)
+    }
+
+    /// Find the points shared by this line and another, or return [`None`] if
+    /// they are distinct parallel lines
+    #[must_use]
+    pub fn intersection(&self, other: &Self) -> Option<LineIntersection<I>> {
+        let denominator = self.direction.clone().perp_dot(other.direction.clone());
+        if denominator.is_zero() {
+            return self
+                .contains(&other.point)
+                .then_some(LineIntersection::Coincident);
+        }
+        let along = (other.point.clone() - self.point.clone()).perp_dot(other.direction.clone());
+        Some(LineIntersection::Point(point_along(
+            self.point.clone(),
+            self.direction.clone(),
+            &along,
+            &denominator,
+        )))
+    }
+}
+
+/// A half-line starting at a point and passing through another, distinct
+/// point
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Ray2<I> {
+    /// The point the ray starts at
+    origin: Vec2D<I>,
+    /// The non-zero difference from the origin to another point on the ray
+    direction: Vec2D<I>,
+}
+
+impl<I> Ray2<I> {
+    /// The point this ray starts at
+    #[must_use]
+    pub const fn origin(&self) -> &Vec2D<I> {
+        &self.origin
+    }
+
+    /// The difference from the origin of this ray to the point it was created
+    /// through, which is never zero
+    #[must_use]
+    pub const fn direction(&self) -> &Vec2D<I> {
+        &self.direction
+    }
+}
+
+impl<I: Integer + Signed + Clone> Ray2<I> {
+    /// Create the [`Ray2`] starting at `origin` and passing through `through`,
+    /// or return [`None`] if they are equal
+    #[must_use]
+    pub fn new(origin: Vec2D<I>, through: Vec2D<I>) -> Option<Self> {
+        if origin == through {
+            return None;
+        }
+        Some(Self {
+            direction: through - origin.clone(),
+            origin,
+        })
+    }
+
+    /// The infinite line containing this ray
+    #[must_use]
+    pub fn line(&self) -> Line2<I> {
+        Line2 {
+            point: self.origin.clone(),
+            direction: self.direction.clone(),
+        }
+    }
+
+    /// Find which side of this ray a point lies on, as the direction of the
+    /// turn made when travelling along the ray and then to the point
+    #[must_use]
+    pub fn orientation(&self, point: &Vec2D<I>) -> Orientation {
+        Orientation::from_cross(
+            &self
+                .direction
+                .clone()
+                .perp_dot(point.clone() - self.origin.clone()),
+        )
+    }
+
+    /// Whether a point lies on this ray
+    #[must_use]
+    pub fn contains(&self, point: &Vec2D<I>) -> bool {
+        self.orientation(point) == Orientation::Collinear
+            && !(point.clone() - self.origin.clone())
+                .dot(self.direction.clone())
+                .is_negative()
+    }
+
+    /// Whether this ray shares any point with a segment
+    #[must_use]
+    pub fn intersects(&self, segment: &Segment2<I>) -> bool {
+        self.intersection(segment).is_some()
+    }
+
+    /// Find the points shared by this ray and a segment, or return [`None`] if
+    /// there are none
+    ///
+    /// If the segment lies along the ray, the overlap runs in the direction of
+    /// the ray.
+    #[must_use]
+    pub fn intersection(&self, segment: &Segment2<I>) -> Option<SegmentIntersection<I>> {
+        let segment_direction = segment.end.clone() - segment.start.clone();
+        let denominator = self.direction.clone().perp_dot(segment_direction.clone());
+        if denominator.is_zero() {
+            return self.parallel_intersection(segment);
+        }
+        // The intersection of the lines through the ray and the segment is at
+        // `along / denominator` of the direction along the ray, and at
+        // `segment_along / denominator` of the way along the segment
+        let offset = segment.start.clone() - self.origin.clone();
+        let mut along = offset.clone().perp_dot(segment_direction);
+        let mut segment_along = offset.perp_dot(self.direction.clone());
+        let mut denominator = denominator;
+        if denominator.is_negative() {
+            along = -along;
+            segment_along = -segment_along;
+            denominator = -denominator;
+        }
+        if along.is_negative() || segment_along.is_negative() || segment_along > denominator {
+            return None;
+        }
+        Some(SegmentIntersection::Point(point_along(
+            self.origin.clone(),
+            self.direction.clone(),
+            &along,
+            &denominator,
+        )))
+    }
+
+    /// Find the points shared by this ray and a segment which is parallel to
+    /// it, including when the segment is a single point
+    fn parallel_intersection(&self, segment: &Segment2<I>) -> Option<SegmentIntersection<I>> {
+        let single =
+            |point: &Vec2D<I>| SegmentIntersection::Point(point.clone().apply(Ratio::from_integer));
+        if segment.start == segment.end {
+            return self
+                .contains(&segment.start)
+                .then(|| single(&segment.start));
+        }
+        if self.orientation(&segment.start) != Orientation::Collinear {
+            return None;
+        }
+        // Order the points by their distance along this ray
+        let key =
+            |point: &Vec2D<I>| (point.clone() - self.origin.clone()).dot(self.direction.clone());
+        let (low, high) = if key(&segment.start) <= key(&segment.end) {
+            (&segment.start, &segment.end)
+        } else {
+            (&segment.end, &segment.start)
+        };
+        if key(high).is_negative() {
+            return None;
+        }
+        let start = if key(low).is_negative() {
+            &self.origin
+        } else {
+            low
+        };
+        if key(start) == key(high) {
+            return Some(single(start));
+        }
+        Some(SegmentIntersection::Overlap(Segment2::new(
+            start.clone(),
+            high.clone(),
+        )))
+    }
+}
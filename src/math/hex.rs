@@ -0,0 +1,409 @@
+//! Hexagonal grids
+//!
+//! This module provides [`Hex`], the coordinates of a cell in a grid of
+//! hexagons. Each cell is stored as the cube coordinates
+//! $\left[\begin{matrix}q&r&s\end{matrix}\right]$ for which $q+r+s=0$, which
+//! makes distances, rotations and line drawing simple, and can be converted to
+//! and from the axial coordinates
+//! $\left[\begin{matrix}q&r\end{matrix}\right]$.
+//!
+//! Hexagons are laid out with either a flat or a pointy top, given by a
+//! [`HexOrientation`], when converting to and from pixel coordinates. Pixel
+//! coordinates have the $y$ axis pointing down, and the centre of the cell
+//! $\left[\begin{matrix}0&0&0\end{matrix}\right]$ at the origin.
+//!
+//! ```
+//! use handyman::math::{
+//!     hex::{Hex, HexOrientation},
+//!     vector::{Vec2D, Vec3D},
+//! };
+//! let origin = Hex::ORIGIN;
+//! let cell = Hex::from_axial(Vec2D(2, -1)).unwrap();
+//! assert_eq!(cell.cube(), Vec3D(2, -1, -1));
+//! assert_eq!(origin.distance(cell), 2);
+//! assert_eq!(origin.neighbors().count(), 6);
+//! assert_eq!(origin.ring(2).count(), 12);
+//! assert_eq!(origin.spiral(2).count(), 19);
+//! assert!(origin.ring(2).all(|hex| hex.distance(origin) == 2));
+//! assert_eq!(cell.rotate_left().rotate_right(), cell);
+//! assert_eq!(origin.line_to(cell).count(), 3);
+//!
+//! let pixel = cell.to_pixel(HexOrientation::PointyTop, 10.0);
+//! assert_eq!(Hex::from_pixel(pixel, HexOrientation::PointyTop, 10.0), Some(cell));
+//! ```
+
+use std::{
+    array, iter,
+    ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign},
+};
+
+use num::ToPrimitive;
+
+use super::{
+    matrix::Mat2,
+    vector::{Vec2D, Vec3D},
+};
+
+/// The offsets to the 6 neighbours of a cell, counter-clockwise starting
+/// from the neighbour towards positive $q$
+const DIRECTIONS: [Vec3D<i64>; 6] = [
+    Vec3D(1, 0, -1),
+    Vec3D(1, -1, 0),
+    Vec3D(0, -1, 1),
+    Vec3D(-1, 0, 1),
+    Vec3D(-1, 1, 0),
+    Vec3D(0, 1, -1),
+];
+
+/// The offsets to the 6 cells which are diagonally adjacent to a cell, i.e.
+/// across the corners between its neighbours
+const DIAGONALS: [Vec3D<i64>; 6] = [
+    Vec3D(2, -1, -1),
+    Vec3D(1, -2, 1),
+    Vec3D(-1, -1, 2),
+    Vec3D(-2, 1, 1),
+    Vec3D(-1, 2, -1),
+    Vec3D(1, 1, -2),
+];
+
+/// The direction in which a line between cells is nudged off the edges
+/// between them, so that it rounds consistently
+const NUDGE: [i8; 3] = [1, 2, -3];
+
+/// Convert an integer to the nearest [`f64`]
+fn to_f64(value: i64) -> f64 {
+    value
+        .to_f64()
+        .unwrap_or_else(|| unreachable!("every i64 has a nearest f64"))
+}
+
+/// The orientation of the hexagons in a grid
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HexOrientation {
+    /// The hexagons have a flat top and bottom, so the cells in each column
+    /// touch
+    FlatTop,
+    /// The hexagons have a pointed top and bottom, so the cells in each row
+    /// touch
+    PointyTop,
+}
+
+impl HexOrientation {
+    /// The matrix converting axial coordinates into pixel coordinates, for
+    /// hexagons with a size (centre to corner distance) of $1$
+    fn pixel_matrix(self) -> Mat2<f64> {
+        let sqrt3 = 3.0_f64.sqrt();
+        match self {
+            Self::FlatTop => Mat2(Vec2D(1.5, 0.0), Vec2D(sqrt3 / 2.0, sqrt3)),
+            Self::PointyTop => Mat2(Vec2D(sqrt3, sqrt3 / 2.0), Vec2D(0.0, 1.5)),
+        }
+    }
+
+    /// The matrix converting pixel coordinates into axial coordinates, for
+    /// hexagons with a size (centre to corner distance) of $1$
+    fn axial_matrix(self) -> Mat2<f64> {
+        let sqrt3 = 3.0_f64.sqrt();
+        match self {
+            Self::FlatTop => Mat2(Vec2D(2.0 / 3.0, 0.0), Vec2D(-1.0 / 3.0, sqrt3 / 3.0)),
+            Self::PointyTop => Mat2(Vec2D(sqrt3 / 3.0, -1.0 / 3.0), Vec2D(0.0, 2.0 / 3.0)),
+        }
+    }
+}
+
+/// The cube coordinates $\left[\begin{matrix}q&r&s\end{matrix}\right]$ of a
+/// cell in a grid of hexagons, for which $q+r+s=0$
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Hex(Vec3D<i64>);
+
+impl Hex {
+    /// The cell at the origin
+    pub const ORIGIN: Self = Self(Vec3D(0, 0, 0));
+
+    /// Create a [`Hex`] from its cube coordinates, or return [`None`] if they
+    /// do not sum to zero
+    ///
+    /// ```
+    /// use handyman::math::{hex::Hex, vector::Vec3D};
+    /// assert!(Hex::from_cube(Vec3D(1, 2, -3)).is_some());
+    /// assert_eq!(Hex::from_cube(Vec3D(1, 2, 3)), None);
+    /// assert_eq!(Hex::from_cube(Vec3D(i64::MAX, i64::MAX, 2)), None);
+    /// ```
+    #[must_use]
+    pub fn from_cube(cube: Vec3D<i64>) -> Option<Self> {
+        let sum = cube.0.checked_add(cube.1)?.checked_add(cube.2)?;
+        (sum == 0).then_some(Self(cube))
+    }
+
+    /// Create a [`Hex`] from its axial coordinates
+    /// $\left[\begin{matrix}q&r\end{matrix}\right]$, or return [`None`] if
+    /// the $s$ coordinate $-q-r$ does not fit in an [`i64`]
+    ///
+    /// ```
+    /// use handyman::math::{hex::Hex, vector::Vec2D};
+    /// assert_eq!(Hex::from_axial(Vec2D(2, -1)).map(Hex::s), Some(-1));
+    /// assert_eq!(Hex::from_axial(Vec2D(i64::MIN, 0)), None);
+    /// ```
+    #[must_use]
+    pub const fn from_axial(axial: Vec2D<i64>) -> Option<Self> {
+        let Some(sum) = axial.0.checked_add(axial.1) else {
+            return None;
+        };
+        match sum.checked_neg() {
+            Some(s_coord) => Some(Self(Vec3D(axial.0, axial.1, s_coord))),
+            None => None,
+        }
+    }
+
+    /// Round fractional cube coordinates to the nearest cell
+    ///
+    /// The coordinates should sum to zero. Returns [`None`] if they are not
+    /// finite or the cell is too large for an [`i64`].
+    #[must_use]
+    pub fn round(cube: Vec3D<f64>) -> Option<Self> {
+        let rounded = cube.apply(f64::round);
+        let error = (rounded - cube).apply(f64::abs);
+        let Vec3D(q_coord, r_coord, s_coord) = rounded.cast::<i64>()?;
+        // Fix up the component with the largest rounding error so that the
+        // coordinates sum to zero
+        let negated_sum = |first: i64, second: i64| first.checked_add(second)?.checked_neg();
+        Some(if error.0 > error.1 && error.0 > error.2 {
+            Self(Vec3D(negated_sum(r_coord, s_coord)?, r_coord, s_coord))
+        } else if error.1 > error.2 {
+            Self(Vec3D(q_coord, negated_sum(q_coord, s_coord)?, s_coord))
+        } else {
+            Self(Vec3D(q_coord, r_coord, negated_sum(q_coord, r_coord)?))
+        })
+    }
+
+    /// The cube coordinates $\left[\begin{matrix}q&r&s\end{matrix}\right]$ of
+    /// this cell
+    #[must_use]
+    pub const fn cube(self) -> Vec3D<i64> {
+        self.0
+    }
+
+    /// The axial coordinates $\left[\begin{matrix}q&r\end{matrix}\right]$ of
+    /// this cell
+    #[must_use]
+    pub const fn axial(self) -> Vec2D<i64> {
+        Vec2D(self.0 .0, self.0 .1)
+    }
+
+    /// The $q$ coordinate of this cell
+    #[must_use]
+    pub const fn q(self) -> i64 {
+        self.0 .0
+    }
+
+    /// The $r$ coordinate of this cell
+    #[must_use]
+    pub const fn r(self) -> i64 {
+        self.0 .1
+    }
+
+    /// The $s$ coordinate of this cell
+    #[must_use]
+    pub const fn s(self) -> i64 {
+        self.0 .2
+    }
+
+    /// The number of steps between this cell and the origin
+    #[must_use]
+    pub fn length(self) -> i64 {
+        self.0.chebyshev_norm()
+    }
+
+    /// The number of steps between two cells
+    #[must_use]
+    pub fn distance(self, other: Self) -> i64 {
+        (self - other).length()
+    }
+
+    /// Iterate over the 6 cells sharing an edge with this one,
+    /// counter-clockwise starting from the neighbour towards positive $q$
+    pub fn neighbors(self) -> impl Iterator<Item = Self> {
+        DIRECTIONS
+            .into_iter()
+            .map(move |offset| self + Self(offset))
+    }
+
+    /// Iterate over the 6 cells diagonally adjacent to this one, across the
+    /// corners between its neighbours
+    pub fn diagonals(self) -> impl Iterator<Item = Self> {
+        DIAGONALS.into_iter().map(move |offset| self + Self(offset))
+    }
+
+    /// Iterate over the cells at a distance of `radius` from this one, which
+    /// is just this cell when `radius` is zero
+    pub fn ring(self, radius: u32) -> impl Iterator<Item = Self> {
+        let radius = i64::from(radius);
+        iter::once(self)
+            .filter(move |_| radius == 0)
+            .chain((0..6).flat_map(move |side| {
+                let corner = self + Self(DIRECTIONS[(side + 4) % 6]) * radius;
+                (0..radius).map(move |step| corner + Self(DIRECTIONS[side]) * step)
+            }))
+    }
+
+    /// Iterate over the cells within a distance of `radius` from this one, ring
+    /// by ring outwards starting from this cell
+    pub fn spiral(self, radius: u32) -> impl Iterator<Item = Self> {
+        (0..=radius).flat_map(move |radius| self.ring(radius))
+    }
+
+    /// Rotate this cell $60\degree$ counter-clockwise about the origin
+    #[must_use]
+    pub const fn rotate_left(self) -> Self {
+        Self(Vec3D(-self.s(), -self.q(), -self.r()))
+    }
+
+    /// Rotate this cell $60\degree$ clockwise about the origin
+    #[must_use]
+    pub const fn rotate_right(self) -> Self {
+        Self(Vec3D(-self.r(), -self.s(), -self.q()))
+    }
+
+    /// Iterate over the cells along the straight line from this cell to
+    /// another, including both
+    ///
+    /// The line is computed with exact integer arithmetic, so it is correct
+    /// for any two cells. Where it runs exactly along the edge between two
+    /// cells, it is nudged off the edge in a consistent direction.
+    ///
+    /// ```
+    /// use handyman::math::{hex::Hex, vector::Vec2D};
+    /// let far = Hex::from_axial(Vec2D(1 << 60, 0)).unwrap();
+    /// let step = Hex::from_axial(Vec2D(1, -1)).unwrap();
+    /// let line: Vec<_> = far.line_to(far + step * 3).collect();
+    /// assert_eq!(line, [far, far + step, far + step * 2, far + step * 3]);
+    /// ```
+    pub fn line_to(self, other: Self) -> impl Iterator<Item = Self> {
+        let start = <[i64; 3]>::from(self.0);
+        let end = <[i64; 3]>::from(other.0);
+        let delta: [i128; 3] =
+            array::from_fn(|axis| i128::from(end[axis]) - i128::from(start[axis]));
+        let steps = delta
+            .map(i128::unsigned_abs)
+            .into_iter()
+            .max()
+            .unwrap_or_default();
+        (0..=steps).map(move |step| Self::round_along(start, delta, step, steps))
+    }
+
+    /// Round the point `step / steps` of the way along the line from `start`
+    /// by `delta` to the nearest cell
+    ///
+    /// This matches [`Hex::round`] on the line nudged by
+    /// $\left[\begin{matrix}\epsilon&2\epsilon&-3\epsilon\end{matrix}\right]$
+    /// for an infinitesimal $\epsilon$, computed exactly. Each coordinate
+    /// is $\frac{\text{remainder}}{\text{steps}}$ past its floor, and each
+    /// rounding error is a multiple of $\frac1{\text{steps}}$ plus a
+    /// multiple of $\epsilon$, which are compared in that order.
+    fn round_along(start: [i64; 3], delta: [i128; 3], step: u128, steps: u128) -> Self {
+        if steps == 0 {
+            return Self(Vec3D::from(start));
+        }
+        let mut rounded = [0; 3];
+        let mut error = [(0, 0); 3];
+        for axis in 0..3 {
+            // Every |delta| * step is below (2^64)^2, so fits in a u128
+            let distance = delta[axis].unsigned_abs() * step;
+            let whole = i128::try_from(distance / steps)
+                .unwrap_or_else(|_| unreachable!("the distance is at most |delta|"));
+            let (floor, remainder) = match distance % steps {
+                remainder if delta[axis] >= 0 => (whole, remainder),
+                0 => (-whole, 0),
+                remainder => (-whole - 1, steps - remainder),
+            };
+            let nudge = NUDGE[axis];
+            let round_up = 2 * remainder > steps || (2 * remainder == steps && nudge > 0);
+            rounded[axis] = i128::from(start[axis]) + floor + i128::from(round_up);
+            error[axis] = if round_up {
+                (steps - remainder, -nudge)
+            } else if remainder == 0 {
+                (0, nudge.abs())
+            } else {
+                (remainder, nudge)
+            };
+        }
+        // Fix up the component with the largest rounding error so that the
+        // coordinates sum to zero
+        let [q_coord, r_coord, s_coord] = rounded;
+        let cube = if error[0] > error[1] && error[0] > error[2] {
+            [-r_coord - s_coord, r_coord, s_coord]
+        } else if error[1] > error[2] {
+            [q_coord, -q_coord - s_coord, s_coord]
+        } else {
+            [q_coord, r_coord, -q_coord - r_coord]
+        };
+        Self(Vec3D::from(cube.map(|coordinate| {
+            i64::try_from(coordinate)
+                .unwrap_or_else(|_| unreachable!("the cells along a line lie between its ends"))
+        })))
+    }
+
+    /// The pixel coordinates of the centre of this cell, for hexagons of the
+    /// given orientation and size (the distance from the centre of a hexagon
+    /// to a corner)
+    #[must_use]
+    pub fn to_pixel(self, orientation: HexOrientation, size: f64) -> Vec2D<f64> {
+        orientation.pixel_matrix() * self.axial().apply(to_f64) * size
+    }
+
+    /// Find the cell containing a point in pixel coordinates, for hexagons of
+    /// the given orientation and size (the distance from the centre of a
+    /// hexagon to a corner)
+    ///
+    /// Returns [`None`] if the point is not finite or is too far away.
+    #[must_use]
+    pub fn from_pixel(pixel: Vec2D<f64>, orientation: HexOrientation, size: f64) -> Option<Self> {
+        let axial = orientation.axial_matrix() * pixel / size;
+        Self::round(Vec3D(axial.0, axial.1, -axial.0 - axial.1))
+    }
+}
+
+impl From<Hex> for Vec3D<i64> {
+    fn from(hex: Hex) -> Self {
+        hex.cube()
+    }
+}
+
+impl Add for Hex {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self::Output {
+        Self(self.0 + rhs.0)
+    }
+}
+
+impl Sub for Hex {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self(self.0 - rhs.0)
+    }
+}
+
+impl Mul<i64> for Hex {
+    type Output = Self;
+    fn mul(self, rhs: i64) -> Self::Output {
+        Self(self.0 * rhs)
+    }
+}
+
+impl Neg for Hex {
+    type Output = Self;
+    fn neg(self) -> Self::Output {
+        Self(-self.0)
+    }
+}
+
+impl AddAssign for Hex {
+    fn add_assign(&mut self, rhs: Self) {
+        self.0 += rhs.0;
+    }
+}
+
+impl SubAssign for Hex {
+    fn sub_assign(&mut self, rhs: Self) {
+        self.0 -= rhs.0;
+    }
+}
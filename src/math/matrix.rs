@@ -0,0 +1,48 @@
+//! Matrices
+//!
+//! This module provides the square matrix types [`Mat2`], [`Mat3`] and
+//! [`Mat4`], whose rows are [`Vec2D`](super::vector::Vec2D)s,
+//! [`Vec3D`](super::vector::Vec3D)s and [`Vec4D`](super::vector::Vec4D)s
+//! respectively. Vectors are treated as column vectors, so a matrix transforms
+//! a vector with `matrix * vector`.
+//!
+//! The const-generic [`Matrix`] provides matrices of any size, whose rows are
+//! [`VecN`](super::vector::VecN)s, along with Gaussian elimination to find
+//! their rank and nullspace and to solve systems of linear equations.
+//!
+//! ```
+//! use handyman::math::{
+//!     matrix::{Mat2, Mat3},
+//!     vector::{Vec2D, Vec3D},
+//! };
+//! use num::rational::Ratio;
+//!
+//! let rotate = Mat2(Vec2D(0, -1), Vec2D(1, 0));
+//! assert_eq!(rotate * Vec2D(1, 2), Vec2D(-2, 1));
+//! assert_eq!(rotate * rotate, Mat2(Vec2D(-1, 0), Vec2D(0, -1)));
+//! assert_eq!(rotate.transpose() * rotate, Mat2::identity());
+//!
+//! let m = Mat3(Vec3D(2, 0, 1), Vec3D(1, 3, 2), Vec3D(1, 1, 2));
+//! assert_eq!(m.determinant(), 6);
+//! assert_eq!(m.trace(), 7);
+//! assert_eq!(m * m.adjugate(), Mat3::<i32>::identity().apply(|one| one * 6));
+//!
+//! let m = m.apply(Ratio::from_integer);
+//! assert_eq!(m * m.inverse().unwrap(), Mat3::identity());
+//!
+//! let singular = Mat2(Vec2D(1.0, 2.0), Vec2D(2.0, 4.0));
+//! assert_eq!(singular.inverse(), None);
+//! ```
+
+mod common;
+mod elimination;
+mod general;
+mod mat2;
+mod mat3;
+mod mat4;
+
+pub use elimination::SolveError;
+pub use general::Matrix;
+pub use mat2::Mat2;
+pub use mat3::Mat3;
+pub use mat4::Mat4;
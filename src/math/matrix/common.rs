@@ -0,0 +1,155 @@
+//! Functionality shared by every square matrix type
+//!
+//! The methods and operators in this module are written once, in terms of each
+//! matrix's `from_fn`, `apply` and `transpose` and its rows, and implemented
+//! for every matrix type with [`impl_common`].
+
+use std::ops::Mul;
+
+use num::{traits::Inv, Num, Signed};
+
+use super::{Mat2, Mat3, Mat4};
+use crate::math::vector::{Vec2D, Vec3D, Vec4D};
+
+/// Map an index into a minor onto an index into the original matrix, from
+/// which the row or column `removed` was removed
+const fn skip(index: usize, removed: usize) -> usize {
+    if index < removed {
+        index
+    } else {
+        index + 1
+    }
+}
+
+/// Implement the shared functionality for a matrix type `$Mat` with `$n` rows
+/// of the vector type `$Vec`, whose minors are of the matrix type `$Minor`
+macro_rules! impl_common {
+    ($Mat:ident, $Vec:ident, $n:tt $(, $Minor:ident)?) => {
+        impl<I: Num> $Mat<I> {
+            /// The identity matrix $I$, with ones on the diagonal and zeros
+            /// everywhere else
+            #[must_use]
+            pub fn identity() -> Self {
+                Self::from_fn(|row, col| if row == col { I::one() } else { I::zero() })
+            }
+
+            /// Compute the trace of this matrix, the sum of the elements on its
+            /// diagonal
+            #[must_use]
+            pub fn trace(self) -> I {
+                <[[I; $n]; $n]>::from(self)
+                    .into_iter()
+                    .enumerate()
+                    .filter_map(|(index, row)| row.into_iter().nth(index))
+                    .fold(I::zero(), I::add)
+            }
+        }
+
+        impl<I: Signed + Clone + Inv<Output = I>> $Mat<I> {
+            /// Compute the inverse $A^{-1}=\frac{\operatorname{adj}A}{\det A}$ of
+            /// this matrix $A$
+            ///
+            /// This is only available for components which can be inverted, such
+            /// as [`num::rational::Ratio`] (for which it is exact) and floats.
+            /// Returns [`None`] if the matrix is singular, i.e. its determinant is
+            /// zero.
+            #[must_use]
+            pub fn inverse(self) -> Option<Self> {
+                let determinant = self.clone().determinant();
+                (!determinant.is_zero()).then(|| {
+                    let scale = determinant.inv();
+                    self.adjugate().apply(|element| element * scale.clone())
+                })
+            }
+        }
+
+        impl<I> From<[[I; $n]; $n]> for $Mat<I> {
+            fn from(rows: [[I; $n]; $n]) -> Self {
+                Self::from(rows.map($Vec::from))
+            }
+        }
+
+        impl<I> From<$Mat<I>> for [[I; $n]; $n] {
+            fn from(matrix: $Mat<I>) -> Self {
+                <[$Vec<I>; $n]>::from(matrix).map(<[I; $n]>::from)
+            }
+        }
+
+        impl<I: Num + Clone> Mul for $Mat<I> {
+            type Output = Self;
+            /// Multiply two matrices
+            fn mul(self, rhs: Self) -> Self::Output {
+                let rows = <[$Vec<I>; $n]>::from(self);
+                let cols = <[$Vec<I>; $n]>::from(rhs.transpose());
+                Self::from_fn(|row, col| rows[row].clone().dot(cols[col].clone()))
+            }
+        }
+
+        impl<I: Num + Clone> Mul<$Vec<I>> for $Mat<I> {
+            type Output = $Vec<I>;
+            /// Multiply this matrix by a column vector
+            fn mul(self, rhs: $Vec<I>) -> Self::Output {
+                let rows = <[$Vec<I>; $n]>::from(self);
+                $Vec::from_fn(|row| rows[row].clone().dot(rhs.clone()))
+            }
+        }
+
+        $(impl_common!(@cofactors $Mat, $n, $Minor);)?
+    };
+
+    // The determinant and adjugate, by cofactor expansion into minors of the
+    // type `$Minor`
+    (@cofactors $Mat:ident, $n:tt, $Minor:ident) => {
+        impl<I: Clone> $Mat<I> {
+            /// Obtain the minor matrix left after removing a row and a column
+            /// from this matrix
+            #[must_use]
+            pub fn minor(&self, row: usize, col: usize) -> $Minor<I> {
+                let elements = <[[I; $n]; $n]>::from(self.clone());
+                $Minor::from_fn(|minor_row, minor_col| {
+                    elements[skip(minor_row, row)][skip(minor_col, col)].clone()
+                })
+            }
+        }
+
+        impl<I: Signed + Clone> $Mat<I> {
+            /// Compute the determinant of this matrix by cofactor expansion
+            /// along its first row
+            #[must_use]
+            pub fn determinant(self) -> I {
+                <[I; $n]>::from(self.0.clone())
+                    .into_iter()
+                    .enumerate()
+                    .fold(I::zero(), |determinant, (col, element)| {
+                        let term = element * self.minor(0, col).determinant();
+                        if col % 2 == 0 {
+                            determinant + term
+                        } else {
+                            determinant - term
+                        }
+                    })
+            }
+
+            /// Compute the adjugate $\operatorname{adj}A$ of this matrix $A$, the
+            /// transpose of its cofactor matrix
+            ///
+            /// Multiplying a matrix by its adjugate yields its determinant times
+            /// the identity, so this is exact even for integer components.
+            #[must_use]
+            pub fn adjugate(self) -> Self {
+                Self::from_fn(|row, col| {
+                    let cofactor = self.minor(col, row).determinant();
+                    if (row + col) % 2 == 0 {
+                        cofactor
+                    } else {
+                        -cofactor
+                    }
+                })
+            }
+        }
+    };
+}
+
+impl_common!(Mat2, Vec2D, 2);
+impl_common!(Mat3, Vec3D, 3, Mat2);
+impl_common!(Mat4, Vec4D, 4, Mat3);
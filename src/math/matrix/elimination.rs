@@ -0,0 +1,182 @@
+//! Gaussian elimination
+//!
+//! The methods in this module row reduce a [`Matrix`] to find its reduced
+//! row-echelon form, rank and nullspace, and to solve systems of linear
+//! equations $Ax=b$. They divide by the elements of the matrix, so they are
+//! intended for components which form a field. Integer components give
+//! meaningless results, as their division truncates, so convert them first.
+//!
+//! The results are only exact for [`Ratio`](num::rational::Ratio) or
+//! [`BigRational`](num::BigRational) components. Each pivot is the entry of
+//! largest absolute value in its column (partial pivoting), which limits the
+//! growth of rounding errors for floats, but an entry only counts as zero if
+//! it is exactly zero. With floats, roundoff can therefore make a singular
+//! matrix appear to have full rank, giving a wrong [`Matrix::rank`] and
+//! [`Matrix::nullspace`] and a meaningless [`Matrix::solve`].
+//!
+//! ```
+//! use handyman::math::{
+//!     matrix::{Matrix, SolveError},
+//!     vector::VecN,
+//! };
+//! use num::rational::Ratio;
+//!
+//! // x + 2y = 5, 3x - y = 1
+//! let a = Matrix::from([[1, 2], [3, -1]]).apply(Ratio::<i128>::from_integer);
+//! let b = VecN([5, 1]).apply(Ratio::from_integer);
+//! assert_eq!(a.solve(b), Ok(VecN([Ratio::new(1, 1), Ratio::new(2, 1)])));
+//!
+//! // 2x + 4y = 2 has no unique solution, and 2x + 4y = 2, x + 2y = 2 has none
+//! let a = Matrix::from([[2, 4], [1, 2]]).apply(Ratio::<i128>::from_integer);
+//! assert_eq!(a.rank(), 1);
+//! assert_eq!(a.nullspace(), [VecN([Ratio::from(-2), Ratio::from(1)])]);
+//! let b = VecN([2, 1]).apply(Ratio::from_integer);
+//! assert_eq!(a.solve(b), Err(SolveError::Singular));
+//! let b = VecN([2, 2]).apply(Ratio::from_integer);
+//! assert_eq!(a.solve(b), Err(SolveError::Inconsistent));
+//!
+//! let m = Matrix::from([[0.0, 2.0, 4.0], [1.0, 1.0, 1.0]]);
+//! assert_eq!(m.rref(), Matrix::from([[1.0, 0.0, -1.0], [0.0, 1.0, 2.0]]));
+//!
+//! // Pivoting on the tiny entry would lose x to rounding
+//! let a = Matrix::from([[1e-20, 1.0], [1.0, 1.0]]);
+//! assert_eq!(a.solve(VecN([1.0, 2.0])), Ok(VecN([1.0, 1.0])));
+//! ```
+
+use std::{error::Error, fmt};
+
+use num::Signed;
+
+use super::Matrix;
+use crate::math::vector::VecN;
+
+/// The error returned when [`Matrix::solve`] cannot find a unique solution
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SolveError {
+    /// The system has infinitely many solutions, as the matrix is singular
+    /// (or has fewer rows than columns) but the equations are consistent
+    Singular,
+    /// The system has no solutions, as its equations contradict each other
+    Inconsistent,
+}
+
+impl fmt::Display for SolveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Singular => f.write_str("the system has infinitely many solutions"),
+            Self::Inconsistent => f.write_str("the system has no solutions"),
+        }
+    }
+}
+
+impl Error for SolveError {}
+
+/// A matrix in reduced row-echelon form, along with a right-hand side column
+/// to which the same row operations were applied
+struct Reduced<I, const R: usize, const C: usize> {
+    /// The rows of the reduced matrix
+    rows: [VecN<I, C>; R],
+    /// The right-hand side column
+    rhs: [I; R],
+    /// The column of the pivot in each nonzero row, in order
+    pivots: Vec<usize>,
+}
+
+impl<I: Signed + PartialOrd + Clone, const R: usize, const C: usize> Matrix<I, R, C> {
+    /// Row reduce this matrix to reduced row-echelon form by Gauss-Jordan
+    /// elimination with partial pivoting, applying the same row operations to
+    /// `rhs`
+    fn reduce(self, VecN(mut rhs): VecN<I, R>) -> Reduced<I, R, C> {
+        let Self(mut rows) = self;
+        let mut pivots = Vec::new();
+        for col in 0..C {
+            let pivot_row = pivots.len();
+            // Pivot on the entry of largest absolute value to limit rounding
+            // errors
+            let Some(found) = (pivot_row..R)
+                .filter(|&row| !rows[row].0[col].is_zero())
+                .reduce(|best, row| {
+                    if rows[row].0[col].abs() > rows[best].0[col].abs() {
+                        row
+                    } else {
+                        best
+                    }
+                })
+            else {
+                continue;
+            };
+            rows.swap(pivot_row, found);
+            rhs.swap(pivot_row, found);
+
+            let pivot = rows[pivot_row].0[col].clone();
+            rows[pivot_row] = rows[pivot_row].clone() / pivot.clone();
+            rhs[pivot_row] = rhs[pivot_row].clone() / pivot;
+
+            for row in (0..R).filter(|&row| row != pivot_row) {
+                let factor = rows[row].0[col].clone();
+                if !factor.is_zero() {
+                    rows[row] = rows[row].clone() - rows[pivot_row].clone() * factor.clone();
+                    rhs[row] = rhs[row].clone() - rhs[pivot_row].clone() * factor;
+                }
+            }
+            pivots.push(col);
+        }
+        Reduced { rows, rhs, pivots }
+    }
+
+    /// Compute the reduced row-echelon form of this matrix
+    #[must_use]
+    pub fn rref(self) -> Self {
+        Self(self.reduce(VecN::zero()).rows)
+    }
+
+    /// Compute the rank of this matrix, the number of linearly independent
+    /// rows (or columns) it has
+    #[must_use]
+    pub fn rank(self) -> usize {
+        self.reduce(VecN::zero()).pivots.len()
+    }
+
+    /// Compute a basis of the nullspace of this matrix $A$, the vectors $x$
+    /// for which $Ax=0$
+    ///
+    /// There is one basis vector for each column without a pivot in the
+    /// reduced row-echelon form, which has a one in that column.
+    #[must_use]
+    pub fn nullspace(self) -> Vec<VecN<I, C>> {
+        let Reduced { rows, pivots, .. } = self.reduce(VecN::zero());
+        (0..C)
+            .filter(|col| !pivots.contains(col))
+            .map(|free| {
+                VecN::from_fn(|col| {
+                    if col == free {
+                        return I::one();
+                    }
+                    pivots
+                        .iter()
+                        .position(|&pivot| pivot == col)
+                        .map_or_else(I::zero, |row| I::zero() - rows[row].0[free].clone())
+                })
+            })
+            .collect()
+    }
+
+    /// Solve the system of linear equations $Ax=b$ for $x$, where $A$ is this
+    /// matrix and $b$ is `rhs`
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SolveError::Inconsistent`] if there is no solution, or
+    /// [`SolveError::Singular`] if there are infinitely many.
+    pub fn solve(self, rhs: VecN<I, R>) -> Result<VecN<I, C>, SolveError> {
+        let Reduced { rhs, pivots, .. } = self.reduce(rhs);
+        if rhs[pivots.len()..].iter().any(|value| !value.is_zero()) {
+            Err(SolveError::Inconsistent)
+        } else if pivots.len() < C {
+            Err(SolveError::Singular)
+        } else {
+            // Every column has a pivot, in the row of the same index
+            Ok(VecN::from_fn(|row| rhs[row].clone()))
+        }
+    }
+}
@@ -0,0 +1,124 @@
+//! General matrices
+//!
+//! This module provides [`Matrix`].
+
+use std::ops::Mul;
+
+use num::Num;
+
+use super::{Mat2, Mat3, Mat4};
+use crate::math::vector::{Vec2D, Vec3D, Vec4D, VecN};
+
+/// An $R\times C$ matrix whose rows are [`VecN`]s with components of a
+/// numeric type `I`
+///
+/// [`Mat2`], [`Mat3`] and [`Mat4`] can be converted to and from [`Matrix`] of
+/// the matching size with [`From`]/[`Into`].
+///
+/// ```
+/// use handyman::math::{
+///     matrix::{Mat2, Matrix},
+///     vector::{Vec2D, VecN},
+/// };
+/// let m = Matrix([VecN([1, 2, 3]), VecN([4, 5, 6])]);
+/// assert_eq!(m * VecN([1, 0, 1]), VecN([4, 10]));
+/// assert_eq!(m.transpose(), Matrix::from([[1, 4], [2, 5], [3, 6]]));
+/// assert_eq!(
+///     Mat2::from(m * m.transpose()),
+///     Mat2(Vec2D(14, 32), Vec2D(32, 77))
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Matrix<I, const R: usize, const C: usize>(pub [VecN<I, C>; R]);
+
+impl<I, const R: usize, const C: usize> Matrix<I, R, C> {
+    /// Create a [`Matrix`] by calling a function $f$ with the row and column of
+    /// each element
+    pub fn from_fn<F>(mut f: F) -> Self
+    where
+        F: FnMut(usize, usize) -> I,
+    {
+        Self(VecN::from_fn(|row| VecN::from_fn(|col| f(row, col))).0)
+    }
+
+    /// Transpose this matrix, swapping its rows and columns
+    #[must_use]
+    pub fn transpose(self) -> Matrix<I, C, R> {
+        let mut elements = self.0.map(|row| row.apply(Some));
+        Matrix::from_fn(|row, col| {
+            elements[col].0[row]
+                .take()
+                .unwrap_or_else(|| unreachable!("each element is moved exactly once"))
+        })
+    }
+
+    /// Apply a function $f$ onto every element of this matrix
+    pub fn apply<F, U>(self, mut f: F) -> Matrix<U, R, C>
+    where
+        F: FnMut(I) -> U,
+    {
+        Matrix(self.0.map(|row| row.apply(&mut f)))
+    }
+}
+
+impl<I: Num, const N: usize> Matrix<I, N, N> {
+    /// The identity matrix $I$, with ones on the diagonal and zeros everywhere
+    /// else
+    #[must_use]
+    pub fn identity() -> Self {
+        Self::from_fn(|row, col| if row == col { I::one() } else { I::zero() })
+    }
+}
+
+impl<I, const R: usize, const C: usize> From<[[I; C]; R]> for Matrix<I, R, C> {
+    fn from(rows: [[I; C]; R]) -> Self {
+        Self(rows.map(VecN))
+    }
+}
+
+impl<I, const R: usize, const C: usize> From<Matrix<I, R, C>> for [[I; C]; R] {
+    fn from(matrix: Matrix<I, R, C>) -> Self {
+        matrix.0.map(|VecN(row)| row)
+    }
+}
+
+impl<I: Num + Clone, const R: usize, const C: usize, const K: usize> Mul<Matrix<I, C, K>>
+    for Matrix<I, R, C>
+{
+    type Output = Matrix<I, R, K>;
+    /// Multiply two matrices
+    fn mul(self, rhs: Matrix<I, C, K>) -> Self::Output {
+        let Matrix(cols) = rhs.transpose();
+        Matrix::from_fn(|row, col| self.0[row].clone().dot(cols[col].clone()))
+    }
+}
+
+impl<I: Num + Clone, const R: usize, const C: usize> Mul<VecN<I, C>> for Matrix<I, R, C> {
+    type Output = VecN<I, R>;
+    /// Multiply this matrix by a column vector
+    fn mul(self, rhs: VecN<I, C>) -> Self::Output {
+        VecN::from_fn(|row| self.0[row].clone().dot(rhs.clone()))
+    }
+}
+
+/// Implement [`From`] between the square matrix type `$Mat` with `$n` rows of
+/// the vector type `$Vec` and [`Matrix`]
+macro_rules! impl_from_square {
+    ($Mat:ident, $Vec:ident, $n:tt) => {
+        impl<I> From<$Mat<I>> for Matrix<I, $n, $n> {
+            fn from(matrix: $Mat<I>) -> Self {
+                Self(<[$Vec<I>; $n]>::from(matrix).map(VecN::from))
+            }
+        }
+
+        impl<I> From<Matrix<I, $n, $n>> for $Mat<I> {
+            fn from(Matrix(rows): Matrix<I, $n, $n>) -> Self {
+                Self::from(rows.map($Vec::from))
+            }
+        }
+    };
+}
+
+impl_from_square!(Mat2, Vec2D, 2);
+impl_from_square!(Mat3, Vec3D, 3);
+impl_from_square!(Mat4, Vec4D, 4);
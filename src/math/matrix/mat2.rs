@@ -0,0 +1,100 @@
+//! 2×2 matrices
+//!
+//! This module provides [`Mat2`].
+
+use num::Signed;
+
+use crate::math::vector::Vec2D;
+
+/// A 2×2 matrix $\left[\begin{matrix}a&b\\c&d\end{matrix}\right]$ whose rows
+/// are [`Vec2D`]s with components of a numeric type `I`
+///
+/// ```
+/// use handyman::math::{matrix::Mat2, vector::Vec2D};
+/// let shear = Mat2(Vec2D(1, 2), Vec2D(0, 1));
+/// assert_eq!(shear * Vec2D(3, 4), Vec2D(11, 4));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Mat2<I>(pub Vec2D<I>, pub Vec2D<I>);
+
+impl<I> Mat2<I> {
+    /// Create a [`Mat2`] by calling a function $f$ with the row and column of
+    /// each element, i.e.
+    /// $\left[\begin{matrix}f(0,0)&f(0,1)\\f(1,0)&f(1,1)\end{matrix}\right]$.
+    pub fn from_fn<F>(mut f: F) -> Self
+    where
+        F: FnMut(usize, usize) -> I,
+    {
+        Self(
+            Vec2D::from_fn(|col| f(0, col)),
+            Vec2D::from_fn(|col| f(1, col)),
+        )
+    }
+
+    /// Create a [`Mat2`] from its columns rather than its rows
+    ///
+    /// ```
+    /// use handyman::math::{matrix::Mat2, vector::Vec2D};
+    /// assert_eq!(
+    ///     Mat2::from_cols(Vec2D(1, 2), Vec2D(3, 4)),
+    ///     Mat2(Vec2D(1, 3), Vec2D(2, 4))
+    /// );
+    /// ```
+    #[must_use]
+    pub fn from_cols(Vec2D(m00, m10): Vec2D<I>, Vec2D(m01, m11): Vec2D<I>) -> Self {
+        Self(Vec2D(m00, m01), Vec2D(m10, m11))
+    }
+
+    /// Transpose this matrix, swapping its rows and columns
+    #[must_use]
+    pub fn transpose(self) -> Self {
+        let Self(row0, row1) = self;
+        Self::from_cols(row0, row1)
+    }
+
+    /// Apply a function $f$ onto every element of this matrix
+    pub fn apply<F, U>(self, mut f: F) -> Mat2<U>
+    where
+        F: FnMut(I) -> U,
+    {
+        Mat2(self.0.apply(&mut f), self.1.apply(&mut f))
+    }
+}
+
+impl<I: Signed + Clone> Mat2<I> {
+    /// Compute the determinant $ad-bc$ of this matrix
+    ///
+    /// ```
+    /// use handyman::math::{matrix::Mat2, vector::Vec2D};
+    /// assert_eq!(Mat2(Vec2D(1, 2), Vec2D(3, 4)).determinant(), -2);
+    /// ```
+    #[must_use]
+    pub fn determinant(self) -> I {
+        let Self(Vec2D(m00, m01), Vec2D(m10, m11)) = self;
+        m00 * m11 - m01 * m10
+    }
+
+    /// Compute the adjugate
+    /// $\left[\begin{matrix}d&-b\\-c&a\end{matrix}\right]$ of this matrix, the
+    /// transpose of its cofactor matrix
+    ///
+    /// Multiplying a matrix by its adjugate yields its determinant times the
+    /// identity, so this is exact even for integer components.
+    #[must_use]
+    pub fn adjugate(self) -> Self {
+        let Self(Vec2D(m00, m01), Vec2D(m10, m11)) = self;
+        Self(Vec2D(m11, -m01), Vec2D(-m10, m00))
+    }
+}
+
+impl<I> From<[Vec2D<I>; 2]> for Mat2<I> {
+    fn from([row0, row1]: [Vec2D<I>; 2]) -> Self {
+        Self(row0, row1)
+    }
+}
+
+impl<I> From<Mat2<I>> for [Vec2D<I>; 2] {
+    fn from(Mat2(row0, row1): Mat2<I>) -> Self {
+        [row0, row1]
+    }
+}
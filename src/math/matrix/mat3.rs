@@ -0,0 +1,83 @@
+//! 3×3 matrices
+//!
+//! This module provides [`Mat3`].
+
+use crate::math::vector::Vec3D;
+
+/// A 3×3 matrix whose rows are [`Vec3D`]s with components of a numeric type
+/// `I`
+///
+/// ```
+/// use handyman::math::{
+///     matrix::Mat3,
+///     vector::{Vec2D, Vec3D},
+/// };
+/// // Translate 2D points by (5, 6) using homogeneous coordinates
+/// let translate = Mat3(Vec3D(1, 0, 5), Vec3D(0, 1, 6), Vec3D(0, 0, 1));
+/// assert_eq!(
+///     Vec2D::from_homogeneous(translate * Vec2D(1, 2).to_homogeneous()),
+///     Some(Vec2D(6, 8))
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Mat3<I>(pub Vec3D<I>, pub Vec3D<I>, pub Vec3D<I>);
+
+impl<I> Mat3<I> {
+    /// Create a [`Mat3`] by calling a function $f$ with the row and column of
+    /// each element
+    pub fn from_fn<F>(mut f: F) -> Self
+    where
+        F: FnMut(usize, usize) -> I,
+    {
+        Self(
+            Vec3D::from_fn(|col| f(0, col)),
+            Vec3D::from_fn(|col| f(1, col)),
+            Vec3D::from_fn(|col| f(2, col)),
+        )
+    }
+
+    /// Create a [`Mat3`] from its columns rather than its rows
+    #[must_use]
+    pub fn from_cols(
+        Vec3D(m00, m10, m20): Vec3D<I>,
+        Vec3D(m01, m11, m21): Vec3D<I>,
+        Vec3D(m02, m12, m22): Vec3D<I>,
+    ) -> Self {
+        Self(
+            Vec3D(m00, m01, m02),
+            Vec3D(m10, m11, m12),
+            Vec3D(m20, m21, m22),
+        )
+    }
+
+    /// Transpose this matrix, swapping its rows and columns
+    #[must_use]
+    pub fn transpose(self) -> Self {
+        let Self(row0, row1, row2) = self;
+        Self::from_cols(row0, row1, row2)
+    }
+
+    /// Apply a function $f$ onto every element of this matrix
+    pub fn apply<F, U>(self, mut f: F) -> Mat3<U>
+    where
+        F: FnMut(I) -> U,
+    {
+        Mat3(
+            self.0.apply(&mut f),
+            self.1.apply(&mut f),
+            self.2.apply(&mut f),
+        )
+    }
+}
+
+impl<I> From<[Vec3D<I>; 3]> for Mat3<I> {
+    fn from([row0, row1, row2]: [Vec3D<I>; 3]) -> Self {
+        Self(row0, row1, row2)
+    }
+}
+
+impl<I> From<Mat3<I>> for [Vec3D<I>; 3] {
+    fn from(Mat3(row0, row1, row2): Mat3<I>) -> Self {
+        [row0, row1, row2]
+    }
+}
@@ -0,0 +1,93 @@
+//! 4×4 matrices
+//!
+//! This module provides [`Mat4`].
+
+use crate::math::vector::Vec4D;
+
+/// A 4×4 matrix whose rows are [`Vec4D`]s with components of a numeric type
+/// `I`
+///
+/// ```
+/// use handyman::math::{
+///     matrix::Mat4,
+///     vector::{Vec3D, Vec4D},
+/// };
+/// // Scale 3D points by 2 and translate them by (1, 2, 3) using homogeneous
+/// // coordinates
+/// let transform = Mat4(
+///     Vec4D(2, 0, 0, 1),
+///     Vec4D(0, 2, 0, 2),
+///     Vec4D(0, 0, 2, 3),
+///     Vec4D(0, 0, 0, 1),
+/// );
+/// assert_eq!(
+///     Vec3D::from_homogeneous(transform * Vec3D(1, 1, 1).to_homogeneous()),
+///     Some(Vec3D(3, 4, 5))
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Mat4<I>(pub Vec4D<I>, pub Vec4D<I>, pub Vec4D<I>, pub Vec4D<I>);
+
+impl<I> Mat4<I> {
+    /// Create a [`Mat4`] by calling a function $f$ with the row and column of
+    /// each element
+    pub fn from_fn<F>(mut f: F) -> Self
+    where
+        F: FnMut(usize, usize) -> I,
+    {
+        Self(
+            Vec4D::from_fn(|col| f(0, col)),
+            Vec4D::from_fn(|col| f(1, col)),
+            Vec4D::from_fn(|col| f(2, col)),
+            Vec4D::from_fn(|col| f(3, col)),
+        )
+    }
+
+    /// Create a [`Mat4`] from its columns rather than its rows
+    #[must_use]
+    pub fn from_cols(
+        Vec4D(m00, m10, m20, m30): Vec4D<I>,
+        Vec4D(m01, m11, m21, m31): Vec4D<I>,
+        Vec4D(m02, m12, m22, m32): Vec4D<I>,
+        Vec4D(m03, m13, m23, m33): Vec4D<I>,
+    ) -> Self {
+        Self(
+            Vec4D(m00, m01, m02, m03),
+            Vec4D(m10, m11, m12, m13),
+            Vec4D(m20, m21, m22, m23),
+            Vec4D(m30, m31, m32, m33),
+        )
+    }
+
+    /// Transpose this matrix, swapping its rows and columns
+    #[must_use]
+    pub fn transpose(self) -> Self {
+        let Self(row0, row1, row2, row3) = self;
+        Self::from_cols(row0, row1, row2, row3)
+    }
+
+    /// Apply a function $f$ onto every element of this matrix
+    pub fn apply<F, U>(self, mut f: F) -> Mat4<U>
+    where
+        F: FnMut(I) -> U,
+    {
+        Mat4(
+            self.0.apply(&mut f),
+            self.1.apply(&mut f),
+            self.2.apply(&mut f),
+            self.3.apply(&mut f),
+        )
+    }
+}
+
+impl<I> From<[Vec4D<I>; 4]> for Mat4<I> {
+    fn from([row0, row1, row2, row3]: [Vec4D<I>; 4]) -> Self {
+        Self(row0, row1, row2, row3)
+    }
+}
+
+impl<I> From<Mat4<I>> for [Vec4D<I>; 4] {
+    fn from(Mat4(row0, row1, row2, row3): Mat4<I>) -> Self {
+        [row0, row1, row2, row3]
+    }
+}
@@ -0,0 +1,336 @@
+//! Quaternions
+//!
+//! This module provides [`Quaternion`], for representing rotations in 3D
+//! space.
+//!
+//! As with the floating-point vector operations, operations which are
+//! undefined for degenerate inputs (such as normalizing or inverting a zero
+//! quaternion) return [`None`] rather than quietly producing NaN.
+//!
+//! ```
+//! use std::f64::consts::FRAC_PI_2;
+//!
+//! use handyman::math::{quaternion::Quaternion, vector::Vec3D};
+//! let quarter_z = Quaternion::from_axis_angle(Vec3D(0.0, 0.0, 2.0), FRAC_PI_2).unwrap();
+//! assert!(quarter_z.rotate(Vec3D(1.0, 0.0, 0.0)).distance(Vec3D(0.0, 1.0, 0.0)) < 1e-12);
+//!
+//! let half_z = quarter_z * quarter_z;
+//! assert!(half_z.rotate(Vec3D(1.0, 2.0, 3.0)).distance(Vec3D(-1.0, -2.0, 3.0)) < 1e-12);
+//!
+//! let eighth_z = Quaternion::identity().slerp(quarter_z, 0.5);
+//! let (axis, angle) = eighth_z.to_axis_angle().unwrap();
+//! assert!(axis.distance(Vec3D(0.0, 0.0, 1.0)) < 1e-12);
+//! assert!((angle - FRAC_PI_2 / 2.0).abs() < 1e-12);
+//! ```
+
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+use num::Float;
+
+use super::{matrix::Mat3, vector::Vec3D};
+
+/// The number two, which [`Float`] has no constant for
+fn two<F: Float>() -> F {
+    F::one() + F::one()
+}
+
+/// A quaternion $w+xi+yj+zk$ backed by a floating-point type `F`, stored as
+/// its scalar part $w$ and its vector part
+/// $\left[\begin{matrix}x&y&z\end{matrix}\right]$
+///
+/// A unit quaternion represents a rotation in 3D space, which can be applied
+/// to a vector with [`Quaternion::rotate`]. Multiplying two quaternions (the
+/// Hamilton product) composes their rotations: `a * b` rotates by `b` and then
+/// by `a`.
+///
+/// ```
+/// use handyman::math::{quaternion::Quaternion, vector::Vec3D};
+/// let i = Quaternion(0.0, Vec3D(1.0, 0.0, 0.0));
+/// let j = Quaternion(0.0, Vec3D(0.0, 1.0, 0.0));
+/// assert_eq!(i * j, Quaternion(0.0, Vec3D(0.0, 0.0, 1.0)));
+/// assert_eq!(j * i, Quaternion(0.0, Vec3D(0.0, 0.0, -1.0)));
+/// assert_eq!(i * i, Quaternion(-1.0, Vec3D(0.0, 0.0, 0.0)));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Quaternion<F>(pub F, pub Vec3D<F>);
+
+impl<F: Float> Quaternion<F> {
+    /// The identity quaternion $1$, which represents no rotation
+    #[must_use]
+    pub fn identity() -> Self {
+        Self(F::one(), Vec3D::zero())
+    }
+
+    /// Create the unit quaternion which rotates by `angle` radians about
+    /// `axis`, counter-clockwise when looking down the axis towards the origin
+    ///
+    /// Returns [`None`] if the axis has zero or non-finite length.
+    #[must_use]
+    pub fn from_axis_angle(axis: Vec3D<F>, angle: F) -> Option<Self> {
+        let (sin, cos) = (angle / two()).sin_cos();
+        axis.normalize().map(|axis| Self(cos, axis * sin))
+    }
+
+    /// Obtain the axis and angle in radians of the rotation represented by
+    /// this unit quaternion, with the angle in $[0, 2\pi]$
+    ///
+    /// Returns [`None`] if this quaternion does not rotate at all, in which
+    /// case the axis is undefined.
+    #[must_use]
+    pub fn to_axis_angle(self) -> Option<(Vec3D<F>, F)> {
+        let Self(w, vector) = self;
+        vector
+            .normalize()
+            .map(|axis| (axis, two::<F>() * vector.length().atan2(w)))
+    }
+
+    /// Create the unit quaternion which rotates by the Euler angles `roll`
+    /// about the $x$ axis, then `pitch` about the $y$ axis, then `yaw` about
+    /// the $z$ axis, all in radians
+    ///
+    /// ```
+    /// use std::f64::consts::FRAC_PI_2;
+    ///
+    /// use handyman::math::{quaternion::Quaternion, vector::Vec3D};
+    /// let rotation = Quaternion::from_euler(FRAC_PI_2, 0.0, FRAC_PI_2);
+    /// assert!(rotation.rotate(Vec3D(0.0, 1.0, 0.0)).distance(Vec3D(0.0, 0.0, 1.0)) < 1e-12);
+    /// assert!(rotation.rotate(Vec3D(1.0, 0.0, 0.0)).distance(Vec3D(0.0, 1.0, 0.0)) < 1e-12);
+    /// ```
+    #[must_use]
+    pub fn from_euler(roll: F, pitch: F, yaw: F) -> Self {
+        let (sin_roll, cos_roll) = (roll / two()).sin_cos();
+        let (sin_pitch, cos_pitch) = (pitch / two()).sin_cos();
+        let (sin_yaw, cos_yaw) = (yaw / two()).sin_cos();
+        Self(
+            cos_roll * cos_pitch * cos_yaw + sin_roll * sin_pitch * sin_yaw,
+            Vec3D(
+                sin_roll * cos_pitch * cos_yaw - cos_roll * sin_pitch * sin_yaw,
+                cos_roll * sin_pitch * cos_yaw + sin_roll * cos_pitch * sin_yaw,
+                cos_roll * cos_pitch * sin_yaw - sin_roll * sin_pitch * cos_yaw,
+            ),
+        )
+    }
+
+    /// Obtain the Euler angles `(roll, pitch, yaw)` in radians of the rotation
+    /// represented by this unit quaternion, as accepted by
+    /// [`Quaternion::from_euler`]
+    ///
+    /// The pitch is in $[-\frac\pi2, \frac\pi2]$ and the roll and yaw are in
+    /// $[-\pi, \pi]$.
+    ///
+    /// ```
+    /// use handyman::math::quaternion::Quaternion;
+    /// let (roll, pitch, yaw) = Quaternion::from_euler(0.1_f64, -0.2, 0.3).to_euler();
+    /// assert!((roll - 0.1).abs() < 1e-12);
+    /// assert!((pitch + 0.2).abs() < 1e-12);
+    /// assert!((yaw - 0.3).abs() < 1e-12);
+    /// ```
+    #[must_use]
+    pub fn to_euler(self) -> (F, F, F) {
+        let Self(w, Vec3D(x, y, z)) = self;
+        let one = F::one();
+        let roll = (two::<F>() * (w * x + y * z)).atan2(one - two::<F>() * (x * x + y * y));
+        let pitch = (two::<F>() * (w * y - z * x)).max(-one).min(one).asin();
+        let yaw = (two::<F>() * (w * z + x * y)).atan2(one - two::<F>() * (y * y + z * z));
+        (roll, pitch, yaw)
+    }
+
+    /// Create the unit quaternion representing the same rotation as a rotation
+    /// matrix
+    ///
+    /// The result is meaningless if the matrix is not a rotation matrix, i.e.
+    /// if it is not orthogonal with determinant $1$.
+    ///
+    /// ```
+    /// use handyman::math::{matrix::Mat3, quaternion::Quaternion, vector::Vec3D};
+    /// let quarter_x = Mat3(
+    ///     Vec3D(1.0, 0.0, 0.0),
+    ///     Vec3D(0.0, 0.0, -1.0),
+    ///     Vec3D(0.0, 1.0, 0.0),
+    /// );
+    /// let rotation = Quaternion::from_matrix(quarter_x);
+    /// let v = Vec3D(1.0, 2.0, 3.0);
+    /// assert!(rotation.rotate(v).distance(quarter_x * v) < 1e-12);
+    /// ```
+    #[must_use]
+    pub fn from_matrix(matrix: Mat3<F>) -> Self {
+        let Mat3(Vec3D(m00, m01, m02), Vec3D(m10, m11, m12), Vec3D(m20, m21, m22)) = matrix;
+        let one = F::one();
+        let four = two::<F>() * two::<F>();
+        // Divide by the largest of the four components to avoid cancellation
+        let trace = m00 + m11 + m22;
+        if trace > F::zero() {
+            let scale = (trace + one).sqrt() * two();
+            Self(scale / four, Vec3D(m21 - m12, m02 - m20, m10 - m01) / scale)
+        } else if m00 > m11 && m00 > m22 {
+            let scale = (one + m00 - m11 - m22).sqrt() * two();
+            Self(
+                (m21 - m12) / scale,
+                Vec3D(scale / four, (m01 + m10) / scale, (m02 + m20) / scale),
+            )
+        } else if m11 > m22 {
+            let scale = (one + m11 - m00 - m22).sqrt() * two();
+            Self(
+                (m02 - m20) / scale,
+                Vec3D((m01 + m10) / scale, scale / four, (m12 + m21) / scale),
+            )
+        } else {
+            let scale = (one + m22 - m00 - m11).sqrt() * two();
+            Self(
+                (m10 - m01) / scale,
+                Vec3D((m02 + m20) / scale, (m12 + m21) / scale, scale / four),
+            )
+        }
+    }
+
+    /// Obtain the rotation matrix representing the same rotation as this unit
+    /// quaternion
+    #[must_use]
+    pub fn to_matrix(self) -> Mat3<F> {
+        let Self(w, Vec3D(x, y, z)) = self;
+        let one = F::one();
+        let two = two::<F>();
+        Mat3(
+            Vec3D(
+                one - two * (y * y + z * z),
+                two * (x * y - z * w),
+                two * (x * z + y * w),
+            ),
+            Vec3D(
+                two * (x * y + z * w),
+                one - two * (x * x + z * z),
+                two * (y * z - x * w),
+            ),
+            Vec3D(
+                two * (x * z - y * w),
+                two * (y * z + x * w),
+                one - two * (x * x + y * y),
+            ),
+        )
+    }
+
+    /// Compute the conjugate $w-xi-yj-zk$ of this quaternion, which for a unit
+    /// quaternion is its inverse
+    #[must_use]
+    pub fn conjugate(self) -> Self {
+        Self(self.0, -self.1)
+    }
+
+    /// Compute the dot product of two quaternions, treating them as 4D vectors
+    #[must_use]
+    pub fn dot(self, other: Self) -> F {
+        self.0 * other.0 + self.1.dot(other.1)
+    }
+
+    /// Compute the norm $\sqrt{w^2+x^2+y^2+z^2}$ of this quaternion
+    #[must_use]
+    pub fn norm(self) -> F {
+        self.dot(self).sqrt()
+    }
+
+    /// Scale this quaternion to have a norm of $1$
+    ///
+    /// Returns [`None`] if the norm is zero or not finite.
+    #[must_use]
+    pub fn normalize(self) -> Option<Self> {
+        let norm = self.norm();
+        (norm.is_finite() && norm > F::zero()).then(|| self / norm)
+    }
+
+    /// Compute the inverse $q^{-1}=\frac{\bar q}{|q|^2}$ of this quaternion
+    /// $q$, for which $qq^{-1}=1$
+    ///
+    /// Returns [`None`] if the norm is zero or not finite.
+    #[must_use]
+    pub fn inverse(self) -> Option<Self> {
+        let norm_squared = self.dot(self);
+        (norm_squared.is_finite() && norm_squared > F::zero())
+            .then(|| self.conjugate() / norm_squared)
+    }
+
+    /// Rotate a vector $\vec v$ by the rotation represented by this unit
+    /// quaternion $q$, computing $q\vec v\bar q$
+    #[must_use]
+    pub fn rotate(self, vector: Vec3D<F>) -> Vec3D<F> {
+        (self * Self(F::zero(), vector) * self.conjugate()).1
+    }
+
+    /// Spherically interpolate between the rotations represented by two unit
+    /// quaternions, along the shortest path
+    ///
+    /// The `weight` is the fraction of the way from `self` to `other`, and
+    /// the rotation proceeds at a constant angular velocity as it varies.
+    #[must_use]
+    pub fn slerp(self, other: Self, weight: F) -> Self {
+        let one = F::one();
+        let cos = self.dot(other);
+        // q and -q represent the same rotation, so take the nearer one
+        let (other, cos) = if cos < F::zero() {
+            (-other, -cos)
+        } else {
+            (other, cos)
+        };
+        if cos > one - F::epsilon().sqrt() {
+            // The quaternions are too close to divide by the sine of the angle
+            // between them, so interpolate linearly instead
+            return (self * (one - weight) + other * weight)
+                .normalize()
+                .unwrap_or(self);
+        }
+        let angle = cos.min(one).acos();
+        let sin = angle.sin();
+        self * (((one - weight) * angle).sin() / sin) + other * ((weight * angle).sin() / sin)
+    }
+}
+
+impl<F: Float> Add for Quaternion<F> {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self::Output {
+        Self(self.0 + rhs.0, self.1 + rhs.1)
+    }
+}
+
+impl<F: Float> Sub for Quaternion<F> {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self(self.0 - rhs.0, self.1 - rhs.1)
+    }
+}
+
+impl<F: Float> Neg for Quaternion<F> {
+    type Output = Self;
+    fn neg(self) -> Self::Output {
+        Self(-self.0, -self.1)
+    }
+}
+
+impl<F: Float> Mul for Quaternion<F> {
+    type Output = Self;
+    /// Compute the Hamilton product of two quaternions
+    fn mul(self, rhs: Self) -> Self::Output {
+        let Self(w1, Vec3D(x1, y1, z1)) = self;
+        let Self(w2, Vec3D(x2, y2, z2)) = rhs;
+        Self(
+            w1 * w2 - x1 * x2 - y1 * y2 - z1 * z2,
+            Vec3D(
+                w1 * x2 + x1 * w2 + y1 * z2 - z1 * y2,
+                w1 * y2 - x1 * z2 + y1 * w2 + z1 * x2,
+                w1 * z2 + x1 * y2 - y1 * x2 + z1 * w2,
+            ),
+        )
+    }
+}
+
+impl<F: Float> Mul<F> for Quaternion<F> {
+    type Output = Self;
+    fn mul(self, rhs: F) -> Self::Output {
+        Self(self.0 * rhs, self.1 * rhs)
+    }
+}
+
+impl<F: Float> Div<F> for Quaternion<F> {
+    type Output = Self;
+    fn div(self, rhs: F) -> Self::Output {
+        Self(self.0 / rhs, self.1 / rhs)
+    }
+}
@@ -1,9 +1,113 @@
 //! Vectors
 //!
 //! This module provides generic vector types.
+//!
+//! # Text format
+//!
+//! Every vector type implements [`Display`](fmt::Display), writing its
+//! components separated by `", "` and surrounded by parentheses, e.g.
+//! `(1, 2)`. The alternate form (`{:#}`) surrounds them with angle brackets
+//! instead, e.g. `<1, 2, 3>`. Any formatting options, such as precision, are
+//! applied to each component.
+//!
+//! Every vector type also implements [`FromStr`](std::str::FromStr), accepting
+//! its components:
+//!
+//! - separated by commas or by whitespace,
+//! - optionally surrounded by `()`, `[]` or `<>`, and
+//! - with optional whitespace around the components and brackets.
+//!
+//! For example, `(1, 2)`, `1,2`, `1 2`, `[1, 2]` and `<1, 2>` all parse as
+//! `Vec2D(1, 2)`. Parsing fails with a [`ParseVectorError`] giving the byte
+//! offset of the problem and, if a component failed to parse, which one.
+//!
+//! ```
+//! use handyman::math::vector::{ParseVectorErrorKind, Vec2D, Vec3D};
+//! assert_eq!(Vec2D(1, 2).to_string(), "(1, 2)");
+//! assert_eq!(format!("{:#}", Vec3D(1, 2, 3)), "<1, 2, 3>");
+//! assert_eq!(format!("{:.1}", Vec2D(0.25, 2.0)), "(0.2, 2.0)");
+//!
+//! assert_eq!("(1, 2)".parse(), Ok(Vec2D(1, 2)));
+//! assert_eq!("1,2".parse(), Ok(Vec2D(1, 2)));
+//! assert_eq!("  1   2 ".parse(), Ok(Vec2D(1, 2)));
+//! assert_eq!("[ -1 , 2 ]".parse(), Ok(Vec2D(-1, 2)));
+//! assert_eq!("<1, 2, 3>".parse(), Ok(Vec3D(1, 2, 3)));
+//!
+//! let error = "(1, x, 3)".parse::<Vec3D<i32>>().unwrap_err();
+//! assert_eq!(error.offset(), 4);
+//! assert!(matches!(
+//!     error.kind(),
+//!     ParseVectorErrorKind::InvalidComponent { index: 1, .. }
+//! ));
+//! assert_eq!(
+//!     error.to_string(),
+//!     "could not parse the y component at byte 4: invalid digit found in string"
+//! );
+//! ```
 
+use std::fmt;
+
+use num::NumCast;
+
+mod cast;
+mod checked;
+mod common;
+mod convert;
+mod display;
+mod float;
+mod line;
+mod metric;
+mod neighbors;
+mod ops;
+mod range;
+mod rotation3;
+mod symmetry;
 mod vec2d;
 mod vec3d;
+mod vec4d;
+mod vecn;
 
+pub use cast::TryFromVectorError;
+pub use display::{ParseVectorError, ParseVectorErrorKind};
+pub use line::{Bresenham2D, Bresenham3D, LatticeLine, Supercover};
+pub use neighbors::Stencil;
+pub use range::{Order, Range2D, Range3D};
+pub use rotation3::Rotation3;
+pub use symmetry::D4;
 pub use vec2d::Vec2D;
 pub use vec3d::Vec3D;
+pub use vec4d::Vec4D;
+pub use vecn::VecN;
+
+/// Convert a component to an [`i128`], in which lattice points are computed
+/// without overflowing
+///
+/// # Panics
+///
+/// Panics if the component cannot be represented by an [`i128`].
+fn widen<I: NumCast>(component: I) -> i128 {
+    num::cast(component).expect("the component should fit in an i128")
+}
+
+/// Convert a component computed by [`widen`]ing back to its original type,
+/// which it fits in because it lies between two components of that type
+fn narrow<I: NumCast>(component: i128) -> I {
+    num::cast(component)
+        .unwrap_or_else(|| unreachable!("the component lies between two components of its type"))
+}
+
+/// The names of the components of [`Vec2D`], [`Vec3D`] and [`Vec4D`], by index
+const AXES: [&str; 4] = ["x", "y", "z", "w"];
+
+/// Formats the name of the component of a vector at an index, e.g. `the x
+/// component` or `component 5`, for use in error messages
+struct ComponentName(usize);
+
+impl fmt::Display for ComponentName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match AXES.get(self.0) {
+            Some(axis) => write!(f, "the {axis} component"),
+            None => write!(f, "component {}", self.0),
+        }
+    }
+}
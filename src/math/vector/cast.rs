@@ -0,0 +1,184 @@
+//! Numeric casts between vector component types
+//!
+//! Every vector type can be converted between component types in three ways:
+//!
+//! - [`From`], where every value of the source component type can be
+//!   represented exactly by the target component type (e.g. `u8` to `i32`),
+//! - [`TryFrom`], between integer component types where the conversion may
+//!   fail, returning a [`TryFromVectorError`] naming the component which did
+//!   not fit, and
+//! - `cast`, built on [`NumCast`], which works between any numeric component
+//!   types but may lose precision (e.g. truncating floats), returning [`None`]
+//!   if any component cannot be represented at all.
+//!
+//! ```
+//! use handyman::math::vector::{Vec2D, Vec3D};
+//! assert_eq!(Vec2D::<i64>::from(Vec2D(1_u8, 2)), Vec2D(1, 2));
+//! assert_eq!(Vec2D::<f64>::from(Vec2D(1_i32, 2)), Vec2D(1.0, 2.0));
+//!
+//! assert_eq!(Vec3D::<u8>::try_from(Vec3D(1_i64, 2, 3)), Ok(Vec3D(1, 2, 3)));
+//! let error = Vec3D::<u8>::try_from(Vec3D(1_i64, 2, -3)).unwrap_err();
+//! assert_eq!(error.index(), 2);
+//! assert_eq!(
+//!     error.to_string(),
+//!     "could not convert the z component: out of range integral type conversion attempted"
+//! );
+//!
+//! assert_eq!(Vec2D(1.5_f64, -2.5).cast::<i32>(), Some(Vec2D(1, -2)));
+//! assert_eq!(Vec2D(1_usize, 300).cast::<u8>(), None);
+//! ```
+
+use std::{convert::identity, error::Error, fmt, num::TryFromIntError};
+
+use num::{NumCast, ToPrimitive};
+
+use super::{ComponentName, Vec2D, Vec3D, Vec4D, VecN};
+
+/// The error returned when [`TryFrom`] fails to convert a component of a
+/// vector
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TryFromVectorError<E> {
+    /// The index of the first component which failed to convert
+    index: usize,
+    /// The error returned when converting that component
+    error: E,
+}
+
+impl<E> TryFromVectorError<E> {
+    /// The index of the first component which failed to convert, where $0$ is
+    /// the $x$ component
+    #[must_use]
+    pub const fn index(&self) -> usize {
+        self.index
+    }
+
+    /// The error returned when converting the component at
+    /// [`TryFromVectorError::index`]
+    #[must_use]
+    pub const fn error(&self) -> &E {
+        &self.error
+    }
+
+    /// Extract the error returned when converting the component at
+    /// [`TryFromVectorError::index`]
+    #[must_use]
+    pub fn into_error(self) -> E {
+        self.error
+    }
+}
+
+impl<E: fmt::Display> fmt::Display for TryFromVectorError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "could not convert {}: {}",
+            ComponentName(self.index),
+            self.error
+        )
+    }
+}
+
+impl<E: Error + 'static> Error for TryFromVectorError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+/// Implement `cast` for a vector type `$Vec`, which is generic over its
+/// component type and optionally over its dimension `$N`
+macro_rules! impl_cast {
+    ($Vec:ident $(, $N:ident)?) => {
+        impl<I: ToPrimitive $(, const $N: usize)?> $Vec<I $(, $N)?> {
+            /// Convert every component of this vector to the numeric type `U`
+            ///
+            /// This may lose precision, for example when converting from a float
+            /// to an integer the fractional part is truncated. Returns [`None`] if
+            /// any component cannot be represented by `U` at all.
+            #[must_use]
+            pub fn cast<U: NumCast>(self) -> Option<$Vec<U $(, $N)?>> {
+                let VecN(components) = VecN::from(self.apply(U::from));
+                components.try_map(identity).map(VecN).map($Vec::from)
+            }
+        }
+    };
+}
+
+impl_cast!(Vec2D);
+impl_cast!(Vec3D);
+impl_cast!(Vec4D);
+impl_cast!(VecN, N);
+
+/// Implement [`From`] between every vector type for each pair of primitive
+/// component types `$from => $to` where the conversion is lossless
+macro_rules! impl_from {
+    ($($from:ty => $($to:ty),+;)*) => {
+        $($(
+            impl_from!(@impl Vec2D; $from => $to);
+            impl_from!(@impl Vec3D; $from => $to);
+            impl_from!(@impl Vec4D; $from => $to);
+            impl_from!(@impl VecN, N; $from => $to);
+        )+)*
+    };
+
+    (@impl $Vec:ident $(, $N:ident)?; $from:ty => $to:ty) => {
+        impl$(<const $N: usize>)? From<$Vec<$from $(, $N)?>> for $Vec<$to $(, $N)?> {
+            fn from(value: $Vec<$from $(, $N)?>) -> Self {
+                value.apply(<$to as From<$from>>::from)
+            }
+        }
+    };
+}
+
+impl_from! {
+    u8 => u16, u32, u64, u128, usize, i16, i32, i64, i128, isize, f32, f64;
+    u16 => u32, u64, u128, usize, i32, i64, i128, f32, f64;
+    u32 => u64, u128, i64, i128, f64;
+    u64 => u128, i128;
+    i8 => i16, i32, i64, i128, isize, f32, f64;
+    i16 => i32, i64, i128, isize, f32, f64;
+    i32 => i64, i128, f64;
+    i64 => i128;
+    f32 => f64;
+}
+
+/// Implement [`TryFrom`] between every vector type for each pair of primitive
+/// integer component types `$from => $to` where the conversion may fail
+macro_rules! impl_try_from {
+    ($($from:ty => $($to:ty),+;)*) => {
+        $($(
+            impl_try_from!(@impl Vec2D; $from => $to);
+            impl_try_from!(@impl Vec3D; $from => $to);
+            impl_try_from!(@impl Vec4D; $from => $to);
+            impl_try_from!(@impl VecN, N; $from => $to);
+        )+)*
+    };
+
+    (@impl $Vec:ident $(, $N:ident)?; $from:ty => $to:ty) => {
+        impl$(<const $N: usize>)? TryFrom<$Vec<$from $(, $N)?>> for $Vec<$to $(, $N)?> {
+            type Error = TryFromVectorError<TryFromIntError>;
+            fn try_from(value: $Vec<$from $(, $N)?>) -> Result<Self, Self::Error> {
+                let VecN(components) =
+                    VecN::from(value).zip_with(VecN::from_fn(identity), |component, index| {
+                        <$to as TryFrom<$from>>::try_from(component)
+                            .map_err(|error| TryFromVectorError { index, error })
+                    });
+                components.try_map(identity).map(VecN).map(Self::from)
+            }
+        }
+    };
+}
+
+impl_try_from! {
+    u8 => i8;
+    u16 => u8, i8, i16, isize;
+    u32 => u8, u16, usize, i8, i16, i32, isize;
+    u64 => u8, u16, u32, usize, i8, i16, i32, i64, isize;
+    u128 => u8, u16, u32, u64, usize, i8, i16, i32, i64, i128, isize;
+    usize => u8, u16, u32, u64, u128, i8, i16, i32, i64, i128, isize;
+    i8 => u8, u16, u32, u64, u128, usize;
+    i16 => u8, u16, u32, u64, u128, usize, i8;
+    i32 => u8, u16, u32, u64, u128, usize, i8, i16, isize;
+    i64 => u8, u16, u32, u64, u128, usize, i8, i16, i32, isize;
+    i128 => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, isize;
+    isize => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128;
+}
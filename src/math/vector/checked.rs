@@ -0,0 +1,184 @@
+//! Checked, saturating, wrapping and overflowing arithmetic for every vector
+//! type
+//!
+//! The methods in this module are written once and implemented for every
+//! vector type with [`impl_checked`]. They mirror the corresponding methods on
+//! the primitive integer types, and are built on the traits in [`num::traits`]:
+//!
+//! - `checked_*` returns [`None`] if any component overflows,
+//! - `saturating_*` clamps each component to the bounds of its type,
+//! - `wrapping_*` wraps each component around the bounds of its type, and
+//! - `overflowing_*` wraps like `wrapping_*`, and also returns whether any
+//!   component overflowed.
+//!
+//! The `*_mul` methods multiply component-wise, and the `*_mul_scalar` methods
+//! multiply every component by a scalar.
+//!
+//! ```
+//! use handyman::math::vector::{Vec2D, Vec3D};
+//! assert_eq!(Vec2D(1_usize, 2).checked_sub(Vec2D(1, 1)), Some(Vec2D(0, 1)));
+//! assert_eq!(Vec2D(1_usize, 2).checked_sub(Vec2D(2, 1)), None);
+//! assert_eq!(Vec3D(1_i8, 2, 3).checked_mul_scalar(50), None);
+//! assert_eq!(Vec2D(i32::MIN, 0).checked_neg(), None);
+//! assert_eq!(Vec2D(250_u8, 5).saturating_add(Vec2D(10, 10)), Vec2D(255, 15));
+//! assert_eq!(Vec2D(1_u8, 5).saturating_sub(Vec2D(3, 3)), Vec2D(0, 2));
+//! assert_eq!(Vec2D(250_u8, 5).wrapping_add(Vec2D(10, 10)), Vec2D(4, 15));
+//! assert_eq!(Vec2D(1_u8, 5).wrapping_neg(), Vec2D(255, 251));
+//! assert_eq!(
+//!     Vec2D(250_u8, 5).overflowing_add(Vec2D(10, 10)),
+//!     (Vec2D(4, 15), true)
+//! );
+//! assert_eq!(
+//!     Vec2D(25_u8, 5).overflowing_mul_scalar(2),
+//!     (Vec2D(50, 10), false)
+//! );
+//! ```
+
+use std::convert::identity;
+
+use num::traits::{
+    ops::overflowing::{OverflowingAdd, OverflowingMul, OverflowingSub},
+    CheckedAdd, CheckedMul, CheckedNeg, CheckedSub, SaturatingAdd, SaturatingMul, SaturatingSub,
+    WrappingAdd, WrappingMul, WrappingNeg, WrappingSub,
+};
+
+use super::{Vec2D, Vec3D, Vec4D, VecN};
+
+/// Implement the checked, saturating, wrapping and overflowing arithmetic for
+/// a vector type `$Vec`, which is generic over its component type and
+/// optionally over its dimension `$N`
+macro_rules! impl_checked {
+    ($Vec:ident $(, $N:ident)?) => {
+        impl_checked!(@binary $Vec $(, $N)?; CheckedAdd OverflowingAdd SaturatingAdd WrappingAdd;
+            checked_add overflowing_add saturating_add wrapping_add; "add");
+        impl_checked!(@binary $Vec $(, $N)?; CheckedSub OverflowingSub SaturatingSub WrappingSub;
+            checked_sub overflowing_sub saturating_sub wrapping_sub; "subtract");
+        impl_checked!(@binary $Vec $(, $N)?; CheckedMul OverflowingMul SaturatingMul WrappingMul;
+            checked_mul overflowing_mul saturating_mul wrapping_mul; "multiply component-wise");
+
+        impl<I: CheckedMul $(, const $N: usize)?> $Vec<I $(, $N)?> {
+            /// Multiply every component by a scalar, returning [`None`] if any
+            /// component overflows
+            #[must_use]
+            pub fn checked_mul_scalar(self, rhs: I) -> Option<Self> {
+                let VecN(components) = VecN::from(self.apply(|lhs| lhs.checked_mul(&rhs)));
+                components.try_map(identity).map(VecN).map(Self::from)
+            }
+        }
+
+        impl<I: OverflowingMul $(, const $N: usize)?> $Vec<I $(, $N)?> {
+            /// Multiply every component by a scalar, wrapping around on
+            /// overflow, and return whether any component overflowed
+            #[must_use]
+            pub fn overflowing_mul_scalar(self, rhs: I) -> (Self, bool) {
+                let mut overflowed = false;
+                let result = self.apply(|lhs| {
+                    let (value, overflow) = lhs.overflowing_mul(&rhs);
+                    overflowed |= overflow;
+                    value
+                });
+                (result, overflowed)
+            }
+        }
+
+        impl<I: SaturatingMul $(, const $N: usize)?> $Vec<I $(, $N)?> {
+            /// Multiply every component by a scalar, saturating at the bounds of
+            /// the component type
+            #[must_use]
+            pub fn saturating_mul_scalar(self, rhs: I) -> Self {
+                self.apply(|lhs| lhs.saturating_mul(&rhs))
+            }
+        }
+
+        impl<I: WrappingMul $(, const $N: usize)?> $Vec<I $(, $N)?> {
+            /// Multiply every component by a scalar, wrapping around at the
+            /// bounds of the component type
+            #[must_use]
+            pub fn wrapping_mul_scalar(self, rhs: I) -> Self {
+                self.apply(|lhs| lhs.wrapping_mul(&rhs))
+            }
+        }
+
+        impl<I: CheckedNeg $(, const $N: usize)?> $Vec<I $(, $N)?> {
+            /// Negate every component, returning [`None`] if any component
+            /// overflows
+            #[must_use]
+            pub fn checked_neg(self) -> Option<Self> {
+                let VecN(components) = VecN::from(self.apply(|component| component.checked_neg()));
+                components.try_map(identity).map(VecN).map(Self::from)
+            }
+        }
+
+        impl<I: WrappingNeg $(, const $N: usize)?> $Vec<I $(, $N)?> {
+            /// Negate every component, wrapping around at the bounds of the
+            /// component type
+            #[must_use]
+            pub fn wrapping_neg(self) -> Self {
+                self.apply(|component| component.wrapping_neg())
+            }
+        }
+    };
+
+    // Checked, overflowing, saturating and wrapping versions of a binary
+    // component-wise operator
+    (
+        @binary $Vec:ident $(, $N:ident)?;
+        $Checked:ident $Overflowing:ident $Saturating:ident $Wrapping:ident;
+        $checked:ident $overflowing:ident $saturating:ident $wrapping:ident;
+        $verb:literal
+    ) => {
+        impl<I: $Checked $(, const $N: usize)?> $Vec<I $(, $N)?> {
+            #[doc = concat!(
+                "Checked ", $verb, ", returning [`None`] if any component overflows"
+            )]
+            #[must_use]
+            pub fn $checked(self, rhs: Self) -> Option<Self> {
+                let VecN(components) =
+                    VecN::from(self.zip_with(rhs, |lhs, rhs| lhs.$checked(&rhs)));
+                components.try_map(identity).map(VecN).map(Self::from)
+            }
+        }
+
+        impl<I: $Overflowing $(, const $N: usize)?> $Vec<I $(, $N)?> {
+            #[doc = concat!(
+                "Overflowing ", $verb, ", wrapping around on overflow, and returning whether ",
+                "any component overflowed"
+            )]
+            #[must_use]
+            pub fn $overflowing(self, rhs: Self) -> (Self, bool) {
+                let mut overflowed = false;
+                let result = self.zip_with(rhs, |lhs, rhs| {
+                    let (value, overflow) = lhs.$overflowing(&rhs);
+                    overflowed |= overflow;
+                    value
+                });
+                (result, overflowed)
+            }
+        }
+
+        impl<I: $Saturating $(, const $N: usize)?> $Vec<I $(, $N)?> {
+            #[doc = concat!(
+                "Saturating ", $verb, ", clamping each component to the bounds of its type"
+            )]
+            #[must_use]
+            pub fn $saturating(self, rhs: Self) -> Self {
+                self.zip_with(rhs, |lhs, rhs| lhs.$saturating(&rhs))
+            }
+        }
+
+        impl<I: $Wrapping $(, const $N: usize)?> $Vec<I $(, $N)?> {
+            #[doc = concat!(
+                "Wrapping ", $verb, ", wrapping each component around the bounds of its type"
+            )]
+            #[must_use]
+            pub fn $wrapping(self, rhs: Self) -> Self {
+                self.zip_with(rhs, |lhs, rhs| lhs.$wrapping(&rhs))
+            }
+        }
+    };
+}
+
+impl_checked!(Vec2D);
+impl_checked!(Vec3D);
+impl_checked!(Vec4D);
+impl_checked!(VecN, N);
@@ -0,0 +1,43 @@
+//! Functionality shared by every vector type
+//!
+//! The methods in this module are written once, in terms of each vector's
+//! `from_fn`, `apply` and `zip_with`, and implemented for every vector type
+//! with [`impl_common`].
+//!
+//! ```
+//! use handyman::math::vector::{Vec2D, Vec3D, VecN};
+//! assert_eq!(Vec2D::zero(), Vec2D(0, 0));
+//! assert_eq!(Vec3D::one(), Vec3D(1, 1, 1));
+//! assert_eq!(VecN::<i32, 4>::zero(), VecN([0; 4]));
+//! ```
+
+use num::Num;
+
+use super::{Vec2D, Vec3D, Vec4D, VecN};
+
+/// Implement the shared inherent methods for a vector type `$Vec`, which is
+/// generic over its component type and optionally over its dimension `$N`
+macro_rules! impl_common {
+    ($Vec:ident $(, $N:ident)?) => {
+        impl<I: Num $(, const $N: usize)?> $Vec<I $(, $N)?> {
+            /// The additive identity vector
+            /// $\left[\begin{matrix}0&\cdots&0\end{matrix}\right]$
+            #[must_use]
+            pub fn zero() -> Self {
+                Self::from_fn(|_| I::zero())
+            }
+
+            /// The multiplicative identity vector
+            /// $\left[\begin{matrix}1&\cdots&1\end{matrix}\right]$
+            #[must_use]
+            pub fn one() -> Self {
+                Self::from_fn(|_| I::one())
+            }
+        }
+    };
+}
+
+impl_common!(Vec2D);
+impl_common!(Vec3D);
+impl_common!(Vec4D);
+impl_common!(VecN, N);
@@ -0,0 +1,157 @@
+//! Conversions between vectors and arrays, slices and iterators
+//!
+//! The conversions in this module are written once and implemented for every
+//! vector type with [`impl_convert`]. Every vector type can be:
+//!
+//! - converted to and from an array of its components with [`From`]/[`Into`],
+//! - converted from a slice of its components with [`TryFrom`], failing if the
+//!   slice has the wrong length,
+//! - iterated over by value, by reference or by mutable reference, and
+//!   collected from an iterator of its components with [`FromIterator`], and
+//! - summed or multiplied from an iterator of vectors with [`Sum`] and
+//!   [`Product`].
+//!
+//! ```
+//! use handyman::math::vector::{Vec2D, Vec3D};
+//! assert_eq!(Vec2D::from([1, 2]), Vec2D(1, 2));
+//! assert_eq!(<[i32; 3]>::from(Vec3D(1, 2, 3)), [1, 2, 3]);
+//! assert_eq!(Vec3D::try_from(&[1, 2, 3][..]).ok(), Some(Vec3D(1, 2, 3)));
+//! assert!(Vec3D::try_from(&[1, 2][..]).is_err());
+//!
+//! assert_eq!(Vec3D(1, 2, 3).iter().sum::<i32>(), 6);
+//! assert_eq!(Vec3D(1, 2, 3).into_iter().rev().collect::<Vec<_>>(), [3, 2, 1]);
+//! assert_eq!((1..=3).collect::<Vec3D<_>>(), Vec3D(1, 2, 3));
+//!
+//! let mut v = Vec2D(1, 2);
+//! for component in &mut v {
+//!     *component *= 10;
+//! }
+//! assert_eq!(v, Vec2D(10, 20));
+//!
+//! let points = [Vec2D(1_i64, 2), Vec2D(3, 4), Vec2D(5, 6)];
+//! assert_eq!(points.iter().copied().sum::<Vec2D<i64>>(), Vec2D(9, 12));
+//! assert_eq!(points.iter().sum::<Vec2D<i64>>(), Vec2D(9, 12));
+//! assert_eq!(points.into_iter().product::<Vec2D<i64>>(), Vec2D(15, 48));
+//! ```
+
+use std::{
+    array::{self, TryFromSliceError},
+    iter::{Product, Sum},
+};
+
+use num::Num;
+
+use super::{Vec2D, Vec3D, Vec4D, VecN};
+
+/// Implement the conversions for a vector type `$Vec` with `$len` components,
+/// which is generic over its component type and optionally over its
+/// dimension `$N`
+macro_rules! impl_convert {
+    ($Vec:ident $(, $N:ident)?; $len:tt) => {
+        impl<I $(, const $N: usize)?> $Vec<I $(, $N)?> {
+            /// Iterate over references to the components of this vector
+            pub fn iter(&self) -> array::IntoIter<&I, $len> {
+                self.each_ref().into_iter()
+            }
+
+            /// Iterate over mutable references to the components of this vector
+            pub fn iter_mut(&mut self) -> array::IntoIter<&mut I, $len> {
+                self.each_mut().into_iter()
+            }
+        }
+
+        impl<I $(, const $N: usize)?> From<[I; $len]> for $Vec<I $(, $N)?> {
+            fn from(components: [I; $len]) -> Self {
+                VecN(components).into()
+            }
+        }
+
+        impl<I $(, const $N: usize)?> From<$Vec<I $(, $N)?>> for [I; $len] {
+            fn from(vector: $Vec<I $(, $N)?>) -> Self {
+                VecN::from(vector).0
+            }
+        }
+
+        impl<I: Clone $(, const $N: usize)?> TryFrom<&[I]> for $Vec<I $(, $N)?> {
+            type Error = TryFromSliceError;
+            fn try_from(components: &[I]) -> Result<Self, Self::Error> {
+                <&[I; $len]>::try_from(components).map(|components| components.clone().into())
+            }
+        }
+
+        impl<I $(, const $N: usize)?> IntoIterator for $Vec<I $(, $N)?> {
+            type Item = I;
+            type IntoIter = array::IntoIter<I, $len>;
+            fn into_iter(self) -> Self::IntoIter {
+                <[I; $len]>::from(self).into_iter()
+            }
+        }
+
+        impl<'a, I $(, const $N: usize)?> IntoIterator for &'a $Vec<I $(, $N)?> {
+            type Item = &'a I;
+            type IntoIter = array::IntoIter<&'a I, $len>;
+            fn into_iter(self) -> Self::IntoIter {
+                self.iter()
+            }
+        }
+
+        impl<'a, I $(, const $N: usize)?> IntoIterator for &'a mut $Vec<I $(, $N)?> {
+            type Item = &'a mut I;
+            type IntoIter = array::IntoIter<&'a mut I, $len>;
+            fn into_iter(self) -> Self::IntoIter {
+                self.iter_mut()
+            }
+        }
+
+        impl<I $(, const $N: usize)?> FromIterator<I> for $Vec<I $(, $N)?> {
+            /// Collect exactly as many components as this vector has from an
+            /// iterator
+            ///
+            /// # Panics
+            ///
+            /// Panics if the iterator yields too few or too many components.
+            fn from_iter<T: IntoIterator<Item = I>>(components: T) -> Self {
+                let mut components = components.into_iter();
+                let vector = Self::from_fn(|_| {
+                    components
+                        .next()
+                        .expect("the iterator should yield a value for every component")
+                });
+                assert!(
+                    components.next().is_none(),
+                    "the iterator should not yield more values than there are components"
+                );
+                vector
+            }
+        }
+
+        impl<I: Num $(, const $N: usize)?> Sum for $Vec<I $(, $N)?> {
+            fn sum<T: Iterator<Item = Self>>(vectors: T) -> Self {
+                vectors.fold(Self::zero(), |sum, vector| sum + vector)
+            }
+        }
+
+        impl<'a, I: Num + Clone $(, const $N: usize)?> Sum<&'a Self> for $Vec<I $(, $N)?> {
+            fn sum<T: Iterator<Item = &'a Self>>(vectors: T) -> Self {
+                vectors.cloned().sum()
+            }
+        }
+
+        impl<I: Num $(, const $N: usize)?> Product for $Vec<I $(, $N)?> {
+            fn product<T: Iterator<Item = Self>>(vectors: T) -> Self {
+                vectors.fold(Self::one(), |product, vector| product * vector)
+            }
+        }
+
+        impl<'a, I: Num + Clone $(, const $N: usize)?> Product<&'a Self> for $Vec<I $(, $N)?> {
+            fn product<T: Iterator<Item = &'a Self>>(vectors: T) -> Self {
+                vectors.cloned().product()
+            }
+        }
+    };
+}
+
+impl_convert!(Vec2D; 2);
+impl_convert!(Vec3D; 3);
+impl_convert!(Vec4D; 4);
+impl_convert!(VecN, N; N);
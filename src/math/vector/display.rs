@@ -0,0 +1,203 @@
+//! Formatting and parsing vectors as text
+//!
+//! The [`Display`](fmt::Display) and [`FromStr`] implementations in this
+//! module are written once and implemented for every vector type with
+//! [`impl_display`]. The text format is documented in the [`vector`](super)
+//! module.
+
+use std::{convert::identity, error::Error, fmt, iter, str::FromStr};
+
+use super::{ComponentName, Vec2D, Vec3D, Vec4D, VecN};
+
+/// The pairs of brackets which may surround a vector
+const BRACKETS: [(char, char); 3] = [('(', ')'), ('[', ']'), ('<', '>')];
+
+/// The error returned when parsing a vector from a string fails
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseVectorError<E> {
+    /// The byte offset into the string at which the problem was found
+    offset: usize,
+    /// What went wrong
+    kind: ParseVectorErrorKind<E>,
+}
+
+/// The ways in which parsing a vector from a string can fail, see
+/// [`ParseVectorError`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseVectorErrorKind<E> {
+    /// An opening bracket was not matched by a closing bracket at the end of
+    /// the string
+    UnmatchedBracket,
+    /// The string ended before every component was found
+    TooFewComponents,
+    /// The string contained more components than the vector has
+    TooManyComponents,
+    /// A component could not be parsed
+    InvalidComponent {
+        /// The index of the component, where $0$ is the $x$ component
+        index: usize,
+        /// The error returned when parsing the component
+        error: E,
+    },
+}
+
+impl<E> ParseVectorError<E> {
+    /// The byte offset into the string at which the problem was found
+    ///
+    /// For [`ParseVectorErrorKind::InvalidComponent`] this is the start of the
+    /// component, and for [`ParseVectorErrorKind::TooManyComponents`] it is the
+    /// start of the first extra component.
+    #[must_use]
+    pub const fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// What went wrong
+    #[must_use]
+    pub const fn kind(&self) -> &ParseVectorErrorKind<E> {
+        &self.kind
+    }
+}
+
+impl<E: fmt::Display> fmt::Display for ParseVectorError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let offset = self.offset;
+        match &self.kind {
+            ParseVectorErrorKind::UnmatchedBracket => {
+                write!(f, "unmatched bracket at byte {offset}")
+            }
+            ParseVectorErrorKind::TooFewComponents => {
+                write!(f, "too few components at byte {offset}")
+            }
+            ParseVectorErrorKind::TooManyComponents => {
+                write!(f, "too many components at byte {offset}")
+            }
+            ParseVectorErrorKind::InvalidComponent { index, error } => {
+                write!(
+                    f,
+                    "could not parse {} at byte {offset}: {error}",
+                    ComponentName(*index)
+                )
+            }
+        }
+    }
+}
+
+impl<E: Error + 'static> Error for ParseVectorError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match &self.kind {
+            ParseVectorErrorKind::InvalidComponent { error, .. } => Some(error),
+            ParseVectorErrorKind::UnmatchedBracket
+            | ParseVectorErrorKind::TooFewComponents
+            | ParseVectorErrorKind::TooManyComponents => None,
+        }
+    }
+}
+
+/// Split `text` on every character matching `is_separator`, yielding each
+/// piece along with its byte offset into `text`
+fn split_with_offsets(
+    text: &str,
+    is_separator: impl Fn(char) -> bool,
+) -> impl Iterator<Item = (usize, &str)> {
+    let mut start = 0;
+    text.char_indices()
+        .filter(move |&(_, character)| is_separator(character))
+        .map(|(end, separator)| (end, separator.len_utf8()))
+        .chain(iter::once((text.len(), 0)))
+        .map(move |(end, separator_len)| {
+            let piece = (start, &text[start..end]);
+            start = end + separator_len;
+            piece
+        })
+}
+
+/// Parse a vector with `N` components from `text` in the format documented in
+/// the [`vector`](super) module
+fn parse<I: FromStr, const N: usize>(text: &str) -> Result<VecN<I, N>, ParseVectorError<I::Err>> {
+    let error = |offset, kind| ParseVectorError { offset, kind };
+
+    let mut offset = text.len() - text.trim_start().len();
+    let mut inner = text.trim();
+    if let Some(&(open, close)) = BRACKETS.iter().find(|(open, _)| inner.starts_with(*open)) {
+        inner = inner
+            .strip_prefix(open)
+            .and_then(|inner| inner.strip_suffix(close))
+            .ok_or_else(|| error(offset, ParseVectorErrorKind::UnmatchedBracket))?;
+        offset += open.len_utf8();
+    }
+
+    let pieces: Vec<(usize, &str)> = if inner.contains(',') {
+        split_with_offsets(inner, |character| character == ',')
+            .map(|(start, piece)| {
+                let leading_whitespace = piece.len() - piece.trim_start().len();
+                (offset + start + leading_whitespace, piece.trim())
+            })
+            .collect()
+    } else {
+        split_with_offsets(inner, char::is_whitespace)
+            .filter(|(_, piece)| !piece.is_empty())
+            .map(|(start, piece)| (offset + start, piece))
+            .collect()
+    };
+
+    let pieces: [(usize, &str); N] = pieces.try_into().map_err(|pieces: Vec<_>| {
+        pieces.get(N).map_or_else(
+            || error(offset + inner.len(), ParseVectorErrorKind::TooFewComponents),
+            |&(start, _)| error(start, ParseVectorErrorKind::TooManyComponents),
+        )
+    })?;
+
+    let VecN(components) =
+        VecN(pieces).zip_with(VecN::from_fn(identity), |(start, piece), index| {
+            piece.parse().map_err(|component_error| {
+                error(
+                    start,
+                    ParseVectorErrorKind::InvalidComponent {
+                        index,
+                        error: component_error,
+                    },
+                )
+            })
+        });
+    components.try_map(identity).map(VecN)
+}
+
+/// Implement [`Display`](fmt::Display) and [`FromStr`] for a vector type
+/// `$Vec` with `$len` components, which is generic over its component type and
+/// optionally over its dimension `$N`
+macro_rules! impl_display {
+    ($Vec:ident $(, $N:ident)?; $len:tt) => {
+        impl<I: fmt::Display $(, const $N: usize)?> fmt::Display for $Vec<I $(, $N)?> {
+            /// Write this vector as its components separated by `", "` and
+            /// surrounded by `()`, or by `<>` in the alternate form (`{:#}`)
+            ///
+            /// Any formatting options are applied to each component.
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                let (open, close) = if f.alternate() { ("<", ">") } else { ("(", ")") };
+                f.write_str(open)?;
+                for (index, component) in self.iter().enumerate() {
+                    if index > 0 {
+                        f.write_str(", ")?;
+                    }
+                    component.fmt(f)?;
+                }
+                f.write_str(close)
+            }
+        }
+
+        impl<I: FromStr $(, const $N: usize)?> FromStr for $Vec<I $(, $N)?> {
+            type Err = ParseVectorError<I::Err>;
+            /// Parse a vector from its components separated by commas or
+            /// whitespace, optionally surrounded by `()`, `[]` or `<>`
+            fn from_str(text: &str) -> Result<Self, Self::Err> {
+                parse::<I, $len>(text).map(Self::from)
+            }
+        }
+    };
+}
+
+impl_display!(Vec2D; 2);
+impl_display!(Vec3D; 3);
+impl_display!(Vec4D; 4);
+impl_display!(VecN, N; N);
@@ -0,0 +1,158 @@
+//! Floating-point operations for every vector type
+//!
+//! The methods in this module are written once and implemented for every
+//! vector type with [`impl_float`]. They are only available for [`Float`]
+//! components.
+//!
+//! Rather than quietly producing NaN, operations which are undefined for
+//! degenerate inputs (such as normalizing or projecting onto a zero-length
+//! vector) return [`None`]. A vector is considered degenerate if its length is
+//! zero or not finite.
+//!
+//! ```
+//! use handyman::math::vector::{Vec2D, Vec3D};
+//! assert_eq!(Vec2D(3.0, 4.0).length(), 5.0);
+//! assert_eq!(Vec2D(3.0, 4.0).normalize(), Some(Vec2D(0.6, 0.8)));
+//! assert_eq!(Vec2D(0.0, 0.0).normalize(), None);
+//! assert_eq!(Vec2D(1e-9, 0.0).try_normalize(1e-6), None);
+//! assert_eq!(
+//!     Vec2D(1.0, 0.0).angle_between(Vec2D(0.0, 2.0)),
+//!     Some(std::f64::consts::FRAC_PI_2)
+//! );
+//! assert_eq!(Vec2D(0.0, 0.0).lerp(Vec2D(2.0, 4.0), 0.25), Vec2D(0.5, 1.0));
+//! assert_eq!(
+//!     Vec3D(1.0, 2.0, 3.0).project_onto(Vec3D(0.0, 0.0, 2.0)),
+//!     Some(Vec3D(0.0, 0.0, 3.0))
+//! );
+//! assert_eq!(
+//!     Vec3D(1.0, 2.0, 3.0).reject_from(Vec3D(0.0, 0.0, 2.0)),
+//!     Some(Vec3D(1.0, 2.0, 0.0))
+//! );
+//! assert_eq!(
+//!     Vec2D(1.0, -1.0).reflect(Vec2D(0.0, 3.0)),
+//!     Some(Vec2D(1.0, 1.0))
+//! );
+//! assert_eq!(Vec2D(1.0, -1.0).reflect(Vec2D(0.0, 0.0)), None);
+//! assert!(Vec2D(1e200, 1e200).normalize().is_some());
+//! ```
+
+use num::Float;
+
+use super::{Vec2D, Vec3D, Vec4D, VecN};
+
+/// Implement the floating-point operations for a vector type `$Vec`, which is
+/// generic over its component type and optionally over its dimension `$N`
+macro_rules! impl_float {
+    ($Vec:ident $(, $N:ident)?) => {
+        impl<I: Float $(, const $N: usize)?> $Vec<I $(, $N)?> {
+            /// Compute the Euclidean length $\|\vec v\|_2=\sqrt{\vec v\cdot\vec
+            /// v}$ of this vector
+            ///
+            /// The components are scaled by the largest of them before being
+            /// squared, so the length does not overflow to infinity (or
+            /// underflow to zero) unless it is itself too large (or small) for
+            /// `I`.
+            #[must_use]
+            pub fn length(self) -> I {
+                // Keep NaN as the scale so that it propagates to the length
+                let scale = self.into_iter().map(I::abs).fold(I::zero(), |scale, component| {
+                    if component > scale || component.is_nan() {
+                        component
+                    } else {
+                        scale
+                    }
+                });
+                if scale.is_zero() || !scale.is_finite() {
+                    return scale;
+                }
+                (self / scale).norm_squared().sqrt() * scale
+            }
+
+            /// Compute the Euclidean distance $\|\vec a-\vec b\|_2$ between two
+            /// vectors
+            #[must_use]
+            pub fn distance(self, other: Self) -> I {
+                (self - other).length()
+            }
+
+            /// Scale this vector to have a length of $1$, yielding
+            /// $\frac{\vec v}{\|\vec v\|_2}$
+            ///
+            /// Returns [`None`] if this vector is degenerate.
+            #[must_use]
+            pub fn normalize(self) -> Option<Self> {
+                self.try_normalize(I::zero())
+            }
+
+            /// Scale this vector to have a length of $1$, yielding
+            /// $\frac{\vec v}{\|\vec v\|_2}$
+            ///
+            /// Returns [`None`] if the length of this vector is not finite or is
+            /// less than or equal to `min_length`.
+            #[must_use]
+            pub fn try_normalize(self, min_length: I) -> Option<Self> {
+                let length = self.length();
+                (length.is_finite() && length > min_length).then(|| self / length)
+            }
+
+            /// Compute the angle $\theta\in[0,\pi]$ between two vectors, i.e.
+            /// $\cos\theta=\frac{\vec a\cdot\vec b}{\|\vec a\|_2\|\vec b\|_2}$
+            ///
+            /// Returns [`None`] if either vector is degenerate.
+            #[must_use]
+            pub fn angle_between(self, other: Self) -> Option<I> {
+                let cos = self.normalize()?.dot(other.normalize()?);
+                // Rounding can push the cosine just outside of [-1, 1]
+                Some(cos.max(-I::one()).min(I::one()).acos())
+            }
+
+            /// Linearly interpolate between two vectors, yielding
+            /// $\vec a+w(\vec b-\vec a)$ for a weight $w$
+            ///
+            /// `weight` is not clamped, so weights below $0$ or above $1$
+            /// extrapolate.
+            #[must_use]
+            pub fn lerp(self, other: Self, weight: I) -> Self {
+                self + (other - self) * weight
+            }
+
+            /// Compute the projection
+            /// $\frac{\vec v\cdot\vec u}{\vec u\cdot\vec u}\vec u$ of this vector
+            /// $\vec v$ onto the vector $\vec u$
+            ///
+            /// Returns [`None`] if $\vec u$ is degenerate.
+            #[must_use]
+            pub fn project_onto(self, onto: Self) -> Option<Self> {
+                let unit = onto.normalize()?;
+                Some(unit * self.dot(unit))
+            }
+
+            /// Compute the rejection of this vector $\vec v$ from the vector
+            /// $\vec u$, i.e. the component of $\vec v$ perpendicular to $\vec
+            /// u$
+            ///
+            /// Returns [`None`] if $\vec u$ is degenerate.
+            #[must_use]
+            pub fn reject_from(self, from: Self) -> Option<Self> {
+                Some(self - self.project_onto(from)?)
+            }
+
+            /// Reflect this vector $\vec v$ across the hyperplane with normal
+            /// $\vec n$, yielding $\vec v-2\frac{\vec v\cdot\vec n}{\vec
+            /// n\cdot\vec n}\vec n$
+            ///
+            /// $\vec n$ does not need to be normalized. Returns [`None`] if $\vec
+            /// n$ is degenerate.
+            #[must_use]
+            pub fn reflect(self, normal: Self) -> Option<Self> {
+                let projection = self.project_onto(normal)?;
+                Some(self - projection * (I::one() + I::one()))
+            }
+        }
+    };
+}
+
+impl_float!(Vec2D);
+impl_float!(Vec3D);
+impl_float!(Vec4D);
+impl_float!(VecN, N);
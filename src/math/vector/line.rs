@@ -0,0 +1,363 @@
+//! Lines between lattice points
+//!
+//! There are three ways to draw a line between two points with integer
+//! components, such as the cells of a grid:
+//!
+//! - [`Vec2D::bresenham`] and [`Vec3D::bresenham`] visit one point for each
+//!   step along the longest axis, giving the thinnest line whose consecutive
+//!   points are orthogonally or diagonally adjacent.
+//! - [`Vec2D::supercover`] visits every cell that the line passes through,
+//!   where each point is the centre of a unit square cell. Consecutive points
+//!   are orthogonally adjacent, except where the line passes exactly through
+//!   the corner between cells, where it steps diagonally.
+//! - [`Vec2D::lattice_line`] visits only the points lying exactly on the line,
+//!   which are evenly spaced.
+//!
+//! Lines are only available for primitive integer components. Every line
+//! starts and ends at its endpoints, and is computed with exact integer
+//! arithmetic.
+//!
+//! ```
+//! use handyman::math::vector::{Vec2D, Vec3D};
+//! let start = Vec2D(0, 0);
+//! let end = Vec2D(4, 2);
+//! assert_eq!(start.bresenham(end).collect::<Vec<_>>(), [
+//!     Vec2D(0, 0),
+//!     Vec2D(1, 0),
+//!     Vec2D(2, 1),
+//!     Vec2D(3, 1),
+//!     Vec2D(4, 2)
+//! ]);
+//! assert_eq!(start.supercover(end).collect::<Vec<_>>(), [
+//!     Vec2D(0, 0),
+//!     Vec2D(1, 0),
+//!     Vec2D(1, 1),
+//!     Vec2D(2, 1),
+//!     Vec2D(3, 1),
+//!     Vec2D(3, 2),
+//!     Vec2D(4, 2)
+//! ]);
+//! assert_eq!(start.lattice_line(end).collect::<Vec<_>>(), [
+//!     Vec2D(0, 0),
+//!     Vec2D(2, 1),
+//!     Vec2D(4, 2)
+//! ]);
+//! assert_eq!(Vec3D(0, 0, 0).bresenham(Vec3D(-3, 1, 2)).len(), 4);
+//! ```
+
+use std::{array, cmp::Ordering, iter::FusedIterator, marker::PhantomData};
+
+use num::{rational::Ratio, Integer, NumCast, PrimInt};
+
+use super::{narrow, widen, Vec2D, Vec3D};
+
+/// The difference from `start` to `end` along an axis
+///
+/// # Panics
+///
+/// Panics if twice the difference does not fit in an [`i128`], which keeps the
+/// error terms of the line algorithms from overflowing.
+fn difference(start: i128, end: i128) -> i128 {
+    end.checked_sub(start)
+        .filter(|difference| difference.checked_mul(2).is_some())
+        .expect("twice the difference between the endpoints should fit in an i128")
+}
+
+/// Implement Bresenham's line algorithm `$Bresenham` for the vector type
+/// `$Vec` with `$n` components
+macro_rules! impl_bresenham {
+    ($Bresenham:ident, $Vec:ident, $n:literal) => {
+        #[doc = concat!(
+            "An iterator over the points of a line drawn with Bresenham's algorithm, see [`",
+            stringify!($Vec), "::bresenham`]"
+        )]
+        #[derive(Debug, Clone)]
+        pub struct $Bresenham<I> {
+            /// The next point to yield
+            point: [i128; $n],
+            /// The direction of the line along each axis, which is $-1$, $0$
+            /// or $1$
+            sign: [i128; $n],
+            /// The absolute difference between the endpoints along each axis
+            delta: [i128; $n],
+            /// The largest component of `delta`, which is the number of steps
+            /// along the line
+            steps: i128,
+            /// Twice the distance from the line along each axis, offset so
+            /// that the point moves along an axis when it is positive
+            error: [i128; $n],
+            /// The number of points left to yield
+            remaining: usize,
+            /// The component type of the points
+            component: PhantomData<fn() -> I>,
+        }
+
+        impl<I: PrimInt> $Vec<I> {
+            /// Iterate over the points of the line from this point to another
+            /// drawn with Bresenham's algorithm, which moves one step along
+            /// the longest axis for each point
+            ///
+            /// This gives the thinnest line whose consecutive points are
+            /// orthogonally or diagonally adjacent.
+            ///
+            /// # Panics
+            ///
+            /// Panics if a component of the endpoints does not fit in an
+            /// [`i128`], if twice the difference between them along an axis
+            /// does not fit in an [`i128`], or if the number of points does
+            /// not fit in a [`usize`].
+            #[must_use]
+            pub fn bresenham(self, end: Self) -> $Bresenham<I> {
+                let start = <[I; $n]>::from(self).map(widen);
+                let end = <[I; $n]>::from(end).map(widen);
+                let difference: [i128; $n] =
+                    array::from_fn(|axis| difference(start[axis], end[axis]));
+                let delta = difference.map(i128::abs);
+                let steps = delta.into_iter().max().unwrap_or_default();
+                $Bresenham {
+                    point: start,
+                    sign: difference.map(i128::signum),
+                    delta,
+                    steps,
+                    error: delta.map(|delta| 2 * delta - steps),
+                    remaining: usize::try_from(steps + 1)
+                        .expect("the number of points should fit in a usize"),
+                    component: PhantomData,
+                }
+            }
+        }
+
+        impl<I: NumCast> Iterator for $Bresenham<I> {
+            type Item = $Vec<I>;
+            fn next(&mut self) -> Option<Self::Item> {
+                self.remaining = self.remaining.checked_sub(1)?;
+                let point = self.point;
+                if self.remaining > 0 {
+                    for axis in 0..$n {
+                        if self.error[axis] > 0 {
+                            self.point[axis] += self.sign[axis];
+                            self.error[axis] -= 2 * self.steps;
+                        }
+                        self.error[axis] += 2 * self.delta[axis];
+                    }
+                }
+                Some($Vec::from(point.map(narrow)))
+            }
+
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                (self.remaining, Some(self.remaining))
+            }
+        }
+
+        impl<I: NumCast> ExactSizeIterator for $Bresenham<I> {}
+
+        impl<I: NumCast> FusedIterator for $Bresenham<I> {}
+    };
+}
+
+impl_bresenham!(Bresenham2D, Vec2D, 2);
+impl_bresenham!(Bresenham3D, Vec3D, 3);
+
+/// An iterator over the cells a line passes through, see
+/// [`Vec2D::supercover`]
+#[derive(Debug, Clone)]
+pub struct Supercover<I> {
+    /// The next point to yield, or [`None`] if the line has ended
+    point: Option<[i128; 2]>,
+    /// The direction of the line along each axis, which is $-1$, $0$ or $1$
+    sign: [i128; 2],
+    /// The absolute difference between the endpoints along each axis
+    delta: [i128; 2],
+    /// The number of steps taken along each axis
+    taken: [i128; 2],
+    /// The component type of the points
+    component: PhantomData<fn() -> I>,
+}
+
+/// An iterator over the points lying exactly on a line, see
+/// [`Vec2D::lattice_line`]
+#[derive(Debug, Clone)]
+pub struct LatticeLine<I> {
+    /// The first point of the line
+    start: [i128; 2],
+    /// The difference between consecutive points
+    step: [i128; 2],
+    /// The index of the next point to yield from the front
+    front: usize,
+    /// The index just after the next point to yield from the back
+    back: usize,
+    /// The component type of the points
+    component: PhantomData<fn() -> I>,
+}
+
+impl<I: PrimInt> Vec2D<I> {
+    /// Iterate over every cell the line from this point to another passes
+    /// through, where each point is the centre of a unit square cell
+    ///
+    /// Consecutive points are orthogonally adjacent, except where the line
+    /// passes exactly through the corner between cells, where it steps
+    /// diagonally.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a component of the endpoints does not fit in an [`i128`], or
+    /// if twice the difference between them along an axis does not fit in an
+    /// [`i128`].
+    #[must_use]
+    pub fn supercover(self, end: Self) -> Supercover<I> {
+        let start = <[I; 2]>::from(self).map(widen);
+        let end = <[I; 2]>::from(end).map(widen);
+        let difference = [difference(start[0], end[0]), difference(start[1], end[1])];
+        Supercover {
+            point: Some(start),
+            sign: difference.map(i128::signum),
+            delta: difference.map(i128::abs),
+            taken: [0, 0],
+            component: PhantomData,
+        }
+    }
+
+    /// Iterate over the points with integer components lying exactly on the
+    /// line from this point to another, which are spaced by the difference
+    /// between the endpoints divided by the greatest common divisor of its
+    /// components
+    ///
+    /// # Panics
+    ///
+    /// Panics if a component of the endpoints does not fit in an [`i128`], if
+    /// twice the difference between them along an axis does not fit in an
+    /// [`i128`], or if the number of points does not fit in a [`usize`].
+    #[must_use]
+    pub fn lattice_line(self, end: Self) -> LatticeLine<I> {
+        let start = <[I; 2]>::from(self).map(widen);
+        let end = <[I; 2]>::from(end).map(widen);
+        let difference = [difference(start[0], end[0]), difference(start[1], end[1])];
+        let divisor = difference[0].gcd(&difference[1]);
+        LatticeLine {
+            start,
+            step: difference.map(
+                |component| {
+                    if divisor == 0 {
+                        0
+                    } else {
+                        component / divisor
+                    }
+                },
+            ),
+            front: 0,
+            back: divisor
+                .checked_add(1)
+                .and_then(|len| usize::try_from(len).ok())
+                .expect("the number of points should fit in a usize"),
+            component: PhantomData,
+        }
+    }
+}
+
+impl<I: NumCast> Iterator for Supercover<I> {
+    type Item = Vec2D<I>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let point = self.point?;
+        let [dx, dy] = self.delta;
+        let [taken_x, taken_y] = self.taken;
+        self.point = if self.taken == self.delta {
+            None
+        } else {
+            // Compare how far along the line it crosses the next vertical and
+            // horizontal edges between cells, as exact fractions of its length
+            // which are compared without overflowing
+            let crossing =
+                |taken: i128, delta| (delta != 0).then(|| Ratio::new_raw(1 + 2 * taken, 2 * delta));
+            let ordering = match (crossing(taken_x, dx), crossing(taken_y, dy)) {
+                (Some(x), Some(y)) => x.cmp(&y),
+                (Some(_), None) => Ordering::Less,
+                (None, _) => Ordering::Greater,
+            };
+            let (move_x, move_y) = match ordering {
+                Ordering::Less => (true, false),
+                Ordering::Equal => (true, true),
+                Ordering::Greater => (false, true),
+            };
+            let mut next = point;
+            if move_x {
+                next[0] += self.sign[0];
+                self.taken[0] += 1;
+            }
+            if move_y {
+                next[1] += self.sign[1];
+                self.taken[1] += 1;
+            }
+            Some(next)
+        };
+        Some(Vec2D::from(point.map(narrow)))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.point.is_none() {
+            return (0, Some(0));
+        }
+        let remaining = [self.delta[0] - self.taken[0], self.delta[1] - self.taken[1]]
+            .map(|remaining| usize::try_from(remaining).ok());
+        let lower = remaining[0]
+            .max(remaining[1])
+            .and_then(|remaining| remaining.checked_add(1));
+        let upper = remaining[0]
+            .zip(remaining[1])
+            .and_then(|(x, y)| x.checked_add(y)?.checked_add(1));
+        (lower.unwrap_or(usize::MAX), upper)
+    }
+}
+
+impl<I: NumCast> FusedIterator for Supercover<I> {}
+
+impl<I: NumCast> LatticeLine<I> {
+    /// The point at an index along the line
+    fn point(&self, index: usize) -> Vec2D<I> {
+        let index =
+            i128::try_from(index).unwrap_or_else(|_| unreachable!("every index fits in an i128"));
+        Vec2D(
+            narrow(self.start[0] + self.step[0] * index),
+            narrow(self.start[1] + self.step[1] * index),
+        )
+    }
+}
+
+impl<I: NumCast> Iterator for LatticeLine<I> {
+    type Item = Vec2D<I>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front == self.back {
+            return None;
+        }
+        self.front += 1;
+        Some(self.point(self.front - 1))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.back - self.front;
+        (len, Some(len))
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.front = self.front.saturating_add(n).min(self.back);
+        self.next()
+    }
+}
+
+impl<I: NumCast> DoubleEndedIterator for LatticeLine<I> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front == self.back {
+            return None;
+        }
+        self.back -= 1;
+        Some(self.point(self.back))
+    }
+
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        self.back = self.back.saturating_sub(n).max(self.front);
+        self.next_back()
+    }
+}
+
+impl<I: NumCast> ExactSizeIterator for LatticeLine<I> {}
+
+impl<I: NumCast> FusedIterator for LatticeLine<I> {}
@@ -0,0 +1,115 @@
+//! Products, norms and distances for every vector type
+//!
+//! The methods in this module are written once and implemented for every
+//! vector type with [`impl_metric`]. They only use exact arithmetic, so they
+//! are exact for integer components, and distances never subtract a larger
+//! component from a smaller one, so they are safe for unsigned components.
+//!
+//! ```
+//! use handyman::math::vector::{Vec2D, Vec3D, VecN};
+//! assert_eq!(Vec2D(1, 2).dot(Vec2D(3, 4)), 11);
+//! assert_eq!(Vec3D(1, -2, 3).manhattan_norm(), 6);
+//! assert_eq!(Vec3D(1, -5, 3).chebyshev_norm(), 5);
+//! assert_eq!(Vec2D(3, 4).norm_squared(), 25);
+//! assert_eq!(Vec2D(1_usize, 5).manhattan_distance(Vec2D(4, 2)), 6);
+//! assert_eq!(Vec2D(1_usize, 5).chebyshev_distance(Vec2D(4, 1)), 4);
+//! assert_eq!(VecN([0_u8, 0, 0]).distance_squared(VecN([1, 2, 2])), 9);
+//! ```
+
+use num::Num;
+
+use super::{Vec2D, Vec3D, Vec4D, VecN};
+
+/// Compute $|a-b|$ without ever subtracting the larger of $a$ and $b$ from the
+/// smaller one
+fn abs_diff<I: Num + PartialOrd>(lhs: I, rhs: I) -> I {
+    if lhs < rhs {
+        rhs - lhs
+    } else {
+        lhs - rhs
+    }
+}
+
+/// Implement the products, norms and distances for a vector type `$Vec`,
+/// which is generic over its component type and optionally over its
+/// dimension `$N`
+macro_rules! impl_metric {
+    ($Vec:ident $(, $N:ident)?) => {
+        impl<I: Num $(, const $N: usize)?> $Vec<I $(, $N)?> {
+            /// Compute the dot product $\vec a\cdot\vec b=\sum_i a_ib_i$ of two
+            /// vectors
+            #[must_use]
+            pub fn dot(self, rhs: Self) -> I {
+                VecN::from(self.zip_with(rhs, I::mul))
+                    .0
+                    .into_iter()
+                    .fold(I::zero(), I::add)
+            }
+        }
+
+        impl<I: Num + Clone $(, const $N: usize)?> $Vec<I $(, $N)?> {
+            /// Compute the squared Euclidean norm $\|\vec v\|_2^2=\vec v\cdot\vec
+            /// v$ of this vector
+            #[must_use]
+            pub fn norm_squared(self) -> I {
+                self.clone().dot(self)
+            }
+        }
+
+        impl<I: Num + PartialOrd $(, const $N: usize)?> $Vec<I $(, $N)?> {
+            /// Compute the Manhattan ($L^1$) norm $\|\vec v\|_1=\sum_i|v_i|$ of
+            /// this vector
+            #[must_use]
+            pub fn manhattan_norm(self) -> I {
+                self.manhattan_distance(Self::zero())
+            }
+
+            /// Compute the Chebyshev ($L^\infty$) norm
+            /// $\|\vec v\|_\infty=\max_i|v_i|$ of this vector
+            #[must_use]
+            pub fn chebyshev_norm(self) -> I {
+                self.chebyshev_distance(Self::zero())
+            }
+
+            /// Compute the Manhattan ($L^1$) distance $\|\vec a-\vec b\|_1$
+            /// between two vectors
+            #[must_use]
+            pub fn manhattan_distance(self, other: Self) -> I {
+                VecN::from(self.zip_with(other, abs_diff))
+                    .0
+                    .into_iter()
+                    .fold(I::zero(), I::add)
+            }
+
+            /// Compute the Chebyshev ($L^\infty$) distance
+            /// $\|\vec a-\vec b\|_\infty$ between two vectors
+            #[must_use]
+            pub fn chebyshev_distance(self, other: Self) -> I {
+                VecN::from(self.zip_with(other, abs_diff))
+                    .0
+                    .into_iter()
+                    .fold(I::zero(), |max, distance| {
+                        if distance > max {
+                            distance
+                        } else {
+                            max
+                        }
+                    })
+            }
+        }
+
+        impl<I: Num + PartialOrd + Clone $(, const $N: usize)?> $Vec<I $(, $N)?> {
+            /// Compute the squared Euclidean distance $\|\vec a-\vec b\|_2^2$
+            /// between two vectors
+            #[must_use]
+            pub fn distance_squared(self, other: Self) -> I {
+                self.zip_with(other, abs_diff).norm_squared()
+            }
+        }
+    };
+}
+
+impl_metric!(Vec2D);
+impl_metric!(Vec3D);
+impl_metric!(Vec4D);
+impl_metric!(VecN, N);
@@ -0,0 +1,299 @@
+//! Neighbourhoods of lattice points
+//!
+//! The methods in this module iterate over the lattice points adjacent to a
+//! [`Vec2D`] or [`Vec3D`], in row-major order (by $z$, then $y$, then $x$).
+//! They use checked arithmetic, so they work with unsigned components and
+//! simply skip any neighbours which would overflow, such as those with a
+//! negative component.
+//!
+//! For other sets of offsets, such as knight moves, use a [`Stencil`].
+//!
+//! ```
+//! use handyman::math::vector::{Stencil, Vec2D, Vec3D};
+//! assert_eq!(
+//!     Vec2D(5, 5).neighbors4().collect::<Vec<_>>(),
+//!     [Vec2D(5, 4), Vec2D(4, 5), Vec2D(6, 5), Vec2D(5, 6)]
+//! );
+//! assert_eq!(
+//!     Vec2D(0_usize, 0).neighbors8().collect::<Vec<_>>(),
+//!     [Vec2D(1, 0), Vec2D(0, 1), Vec2D(1, 1)]
+//! );
+//! assert_eq!(Vec3D(1, 1, 1).neighbors6().count(), 6);
+//! assert_eq!(Vec3D(1, 1, 1).neighbors26().count(), 26);
+//!
+//! let knight = Stencil::knight();
+//! assert_eq!(knight.len(), 8);
+//! assert!(knight.neighbors(Vec2D(4, 4)).any(|square| square == Vec2D(5, 6)));
+//! ```
+
+use std::convert::identity;
+
+use num::{CheckedAdd, CheckedSub, NumCast, One};
+
+use super::{Vec2D, Vec3D, VecN};
+use crate::math::direction::Direction8;
+
+/// The offsets to the 4 orthogonally adjacent points in two dimensions
+const NEIGHBORS4: [[i8; 2]; 4] = [[0, -1], [-1, 0], [1, 0], [0, 1]];
+
+/// The offsets to the 8 orthogonally or diagonally adjacent points in two
+/// dimensions
+const NEIGHBORS8: [[i8; 2]; 8] = [
+    [-1, -1],
+    [0, -1],
+    [1, -1],
+    [-1, 0],
+    [1, 0],
+    [-1, 1],
+    [0, 1],
+    [1, 1],
+];
+
+/// The offsets of the 8 moves of a knight in chess
+const KNIGHT: [[i8; 2]; 8] = [
+    [-1, -2],
+    [1, -2],
+    [-2, -1],
+    [2, -1],
+    [-2, 1],
+    [2, 1],
+    [-1, 2],
+    [1, 2],
+];
+
+/// The offsets to the 6 orthogonally adjacent points in three dimensions
+const NEIGHBORS6: [[i8; 3]; 6] = [
+    [0, 0, -1],
+    [0, -1, 0],
+    [-1, 0, 0],
+    [1, 0, 0],
+    [0, 1, 0],
+    [0, 0, 1],
+];
+
+/// The offsets to the 26 points in the $3\times3\times3$ cube around a point
+/// in three dimensions
+const NEIGHBORS26: [[i8; 3]; 26] = {
+    let mut offsets = [[0; 3]; 26];
+    let mut cell = 0;
+    let mut index = 0;
+    while cell < 27 {
+        // Skip the centre of the cube
+        if cell != 13 {
+            offsets[index] = [cell % 3 - 1, cell / 3 % 3 - 1, cell / 9 - 1];
+            index += 1;
+        }
+        cell += 1;
+    }
+    offsets
+};
+
+/// Offset a component by a `delta` of $-1$, $0$ or $1$, returning [`None`] on
+/// overflow
+fn step<I: CheckedAdd + CheckedSub + One + Clone>(component: &I, delta: i8) -> Option<I> {
+    match delta {
+        -1 => component.checked_sub(&I::one()),
+        0 => Some(component.clone()),
+        _ => component.checked_add(&I::one()),
+    }
+}
+
+/// Offset a component by any `offset`, returning [`None`] if the result does
+/// not fit in the component type
+fn shift<I: CheckedAdd + CheckedSub + NumCast + Clone>(component: &I, offset: i64) -> Option<I> {
+    match num::cast::<u64, I>(offset.unsigned_abs()) {
+        Some(magnitude) if offset < 0 => component.checked_sub(&magnitude),
+        Some(magnitude) => component.checked_add(&magnitude),
+        // The offset does not fit in the component type, which is then
+        // narrow enough for the component and the result to fit in an i128
+        None => {
+            num::cast(num::cast::<I, i128>(component.clone())? + <i128 as From<i64>>::from(offset))
+        }
+    }
+}
+
+impl<I: CheckedAdd + CheckedSub + One + Clone> Vec2D<I> {
+    /// Iterate over the 4 points orthogonally adjacent to this one (its von
+    /// Neumann neighbourhood)
+    pub fn neighbors4(self) -> impl Iterator<Item = Self> {
+        NEIGHBORS4
+            .into_iter()
+            .filter_map(move |[dx, dy]| Some(Self(step(&self.0, dx)?, step(&self.1, dy)?)))
+    }
+
+    /// Iterate over the 8 points orthogonally or diagonally adjacent to this
+    /// one (its Moore neighbourhood)
+    pub fn neighbors8(self) -> impl Iterator<Item = Self> {
+        NEIGHBORS8
+            .into_iter()
+            .filter_map(move |[dx, dy]| Some(Self(step(&self.0, dx)?, step(&self.1, dy)?)))
+    }
+
+    /// Move to the adjacent point in a direction, with the $y$ axis pointing
+    /// down, or return [`None`] if a component overflows
+    ///
+    /// Unlike `position + direction`, this works for unsigned components, so
+    /// it can step between the positions of a [`Grid2D`](crate::grid::Grid2D)
+    /// without going below zero.
+    #[must_use]
+    pub fn checked_add_dir<D: Into<Direction8>>(self, direction: D) -> Option<Self> {
+        let (dx, dy) = direction.into().offset();
+        Some(Self(step(&self.0, dx)?, step(&self.1, dy)?))
+    }
+
+    /// Move to the adjacent point opposite a direction, with the $y$ axis
+    /// pointing down, or return [`None`] if a component overflows
+    #[must_use]
+    pub fn checked_sub_dir<D: Into<Direction8>>(self, direction: D) -> Option<Self> {
+        self.checked_add_dir(direction.into().reverse())
+    }
+}
+
+impl<I: CheckedAdd + CheckedSub + One + Clone> Vec3D<I> {
+    /// Iterate over the 6 points orthogonally adjacent to this one (its von
+    /// Neumann neighbourhood)
+    pub fn neighbors6(self) -> impl Iterator<Item = Self> {
+        NEIGHBORS6.into_iter().filter_map(move |[dx, dy, dz]| {
+            Some(Self(
+                step(&self.0, dx)?,
+                step(&self.1, dy)?,
+                step(&self.2, dz)?,
+            ))
+        })
+    }
+
+    /// Iterate over the 26 points orthogonally or diagonally adjacent to this
+    /// one (its Moore neighbourhood)
+    pub fn neighbors26(self) -> impl Iterator<Item = Self> {
+        NEIGHBORS26.into_iter().filter_map(move |[dx, dy, dz]| {
+            Some(Self(
+                step(&self.0, dx)?,
+                step(&self.1, dy)?,
+                step(&self.2, dz)?,
+            ))
+        })
+    }
+}
+
+/// A set of offsets defining the neighbours of a point, such as the moves of
+/// a knight in chess
+///
+/// The offsets are vectors with [`i64`] components, such as [`Vec2D<i64>`],
+/// and are added to a point with checked arithmetic to find its neighbours.
+/// Neighbours which do not fit in the component type of the point, such as
+/// those with a negative component for a [`Vec2D<usize>`], are skipped.
+///
+/// ```
+/// use handyman::math::vector::{Stencil, Vec2D};
+/// // Moves which jump over a neighbouring cell
+/// let jumps = Stencil::new([Vec2D(0, -2), Vec2D(2, 0), Vec2D(0, 2), Vec2D(-2, 0)]);
+/// assert_eq!(
+///     jumps.neighbors(Vec2D(3, 3)).collect::<Vec<_>>(),
+///     [Vec2D(3, 1), Vec2D(5, 3), Vec2D(3, 5), Vec2D(1, 3)]
+/// );
+/// assert_eq!(
+///     jumps.neighbors(Vec2D(1_usize, 0)).collect::<Vec<_>>(),
+///     [Vec2D(3, 0), Vec2D(1, 2)]
+/// );
+/// assert_eq!(jumps.neighbors(Vec2D(u8::MAX, 1)).count(), 2);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Stencil<V> {
+    /// The offsets from a point to each of its neighbours
+    offsets: Vec<V>,
+}
+
+impl<V> Stencil<V> {
+    /// Create a [`Stencil`] from the offsets from a point to each of its
+    /// neighbours
+    pub fn new<T: IntoIterator<Item = V>>(offsets: T) -> Self {
+        Self {
+            offsets: offsets.into_iter().collect(),
+        }
+    }
+
+    /// The offsets from a point to each of its neighbours
+    #[must_use]
+    pub fn offsets(&self) -> &[V] {
+        &self.offsets
+    }
+
+    /// The number of offsets in this stencil
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.offsets.len()
+    }
+
+    /// Whether this stencil has no offsets
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.offsets.is_empty()
+    }
+}
+
+/// Implement applying a [`Stencil`] to points of the vector type `$Vec`
+macro_rules! impl_stencil {
+    ($Vec:ident) => {
+        impl Stencil<$Vec<i64>> {
+            /// Iterate over the neighbours of a point, adding each offset to it
+            /// in order, and skipping any neighbour which does not fit in the
+            /// component type
+            pub fn neighbors<'a, I>(&'a self, center: $Vec<I>) -> impl Iterator<Item = $Vec<I>> + 'a
+            where
+                I: CheckedAdd + CheckedSub + NumCast + Clone + 'a,
+            {
+                self.offsets.iter().filter_map(move |offset| {
+                    let VecN(components) = VecN::from(
+                        center
+                            .clone()
+                            .zip_with(offset.clone(), |component, offset| {
+                                shift(&component, offset)
+                            }),
+                    );
+                    components.try_map(identity).map(VecN).map($Vec::from)
+                })
+            }
+        }
+    };
+}
+
+impl_stencil!(Vec2D);
+impl_stencil!(Vec3D);
+
+impl Stencil<Vec2D<i64>> {
+    /// The offsets to the 4 orthogonally adjacent points, as used by
+    /// [`Vec2D::neighbors4`]
+    #[must_use]
+    pub fn von_neumann() -> Self {
+        Self::new(NEIGHBORS4.map(|[dx, dy]| Vec2D(dx, dy).apply(Into::into)))
+    }
+
+    /// The offsets to the 8 orthogonally or diagonally adjacent points, as
+    /// used by [`Vec2D::neighbors8`]
+    #[must_use]
+    pub fn moore() -> Self {
+        Self::new(NEIGHBORS8.map(|[dx, dy]| Vec2D(dx, dy).apply(Into::into)))
+    }
+
+    /// The offsets of the 8 moves of a knight in chess, in row-major order
+    #[must_use]
+    pub fn knight() -> Self {
+        Self::new(KNIGHT.map(|[dx, dy]| Vec2D(dx, dy).apply(Into::into)))
+    }
+}
+
+impl Stencil<Vec3D<i64>> {
+    /// The offsets to the 6 orthogonally adjacent points, as used by
+    /// [`Vec3D::neighbors6`]
+    #[must_use]
+    pub fn von_neumann() -> Self {
+        Self::new(NEIGHBORS6.map(|[dx, dy, dz]| Vec3D(dx, dy, dz).apply(Into::into)))
+    }
+
+    /// The offsets to the 26 orthogonally or diagonally adjacent points, as
+    /// used by [`Vec3D::neighbors26`]
+    #[must_use]
+    pub fn moore() -> Self {
+        Self::new(NEIGHBORS26.map(|[dx, dy, dz]| Vec3D(dx, dy, dz).apply(Into::into)))
+    }
+}
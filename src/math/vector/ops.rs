@@ -0,0 +1,142 @@
+//! Arithmetic operators for every vector type
+//!
+//! The operators in this module are written once, in terms of each vector's
+//! `apply`, `zip_with`, `each_ref` and `each_mut`, and implemented for every
+//! vector type with [`impl_ops`].
+//!
+//! Every vector type supports:
+//!
+//! - component-wise `+`, `-`, `*`, `/` and `%` between two vectors, both by
+//!   value and by reference (so non-[`Copy`] components like [`num::BigInt`]
+//!   work with `&a + &b`),
+//! - `*`, `/` and `%` by a scalar on the right, and `*` by a scalar on the left
+//!   for the primitive numeric types,
+//! - every matching `*Assign` operator, and
+//! - unary `-`.
+//!
+//! ```
+//! use handyman::math::vector::{Vec2D, Vec3D, VecN};
+//! assert_eq!(Vec2D(1, 3) * 2, Vec2D(2, 6));
+//! assert_eq!(2 * Vec2D(1, 3), Vec2D(2, 6));
+//! assert_eq!(-Vec3D(1, -2, 3), Vec3D(-1, 2, -3));
+//! assert_eq!(VecN([1, 2, 3, 4]) + VecN([4, 3, 2, 1]), VecN([5; 4]));
+//! assert_eq!(Vec2D(7, 9) - Vec2D(1, 2), Vec2D(6, 7));
+//! assert_eq!(Vec2D(7, 9) * Vec2D(2, 3), Vec2D(14, 27));
+//! assert_eq!(Vec2D(7, 9) / Vec2D(2, 3), Vec2D(3, 3));
+//! assert_eq!(Vec2D(7, 9) % 4, Vec2D(3, 1));
+//!
+//! let mut v = Vec3D(1, 2, 3);
+//! v += Vec3D(1, 1, 1);
+//! v *= 3;
+//! v -= &Vec3D(1, 1, 1);
+//! assert_eq!(v, Vec3D(5, 8, 11));
+//!
+//! use num::BigInt;
+//! let a = Vec2D(BigInt::from(1), BigInt::from(2));
+//! let b = Vec2D(BigInt::from(3), BigInt::from(4));
+//! assert_eq!(&a + &b, Vec2D(BigInt::from(4), BigInt::from(6)));
+//! ```
+
+use std::ops::{
+    Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign,
+};
+
+use super::{Vec2D, Vec3D, Vec4D, VecN};
+
+/// Implement the arithmetic operators for a vector type `$Vec`, which is
+/// generic over its component type and optionally over its dimension `$N`
+macro_rules! impl_ops {
+    ($Vec:ident $(, $N:ident)?) => {
+        impl_ops!(@vector $Vec $(, $N)?; Add add AddAssign add_assign);
+        impl_ops!(@vector $Vec $(, $N)?; Sub sub SubAssign sub_assign);
+        impl_ops!(@vector $Vec $(, $N)?; Mul mul MulAssign mul_assign);
+        impl_ops!(@vector $Vec $(, $N)?; Div div DivAssign div_assign);
+        impl_ops!(@vector $Vec $(, $N)?; Rem rem RemAssign rem_assign);
+
+        impl_ops!(@scalar $Vec $(, $N)?; Mul mul MulAssign mul_assign);
+        impl_ops!(@scalar $Vec $(, $N)?; Div div DivAssign div_assign);
+        impl_ops!(@scalar $Vec $(, $N)?; Rem rem RemAssign rem_assign);
+
+        impl_ops!(
+            @scalar_lhs $Vec $(, $N)?;
+            i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64
+        );
+
+        impl<I: Neg<Output = I> $(, const $N: usize)?> Neg for $Vec<I $(, $N)?> {
+            type Output = Self;
+            /// Negate every component of this vector $\vec v$, yielding $-\vec v$.
+            fn neg(self) -> Self::Output {
+                self.apply(I::neg)
+            }
+        }
+    };
+
+    // Component-wise operators between two vectors
+    (@vector $Vec:ident $(, $N:ident)?; $Op:ident $op:ident $OpAssign:ident $op_assign:ident) => {
+        impl<I: $Op<Output = I> $(, const $N: usize)?> $Op for $Vec<I $(, $N)?> {
+            type Output = Self;
+            fn $op(self, rhs: Self) -> Self::Output {
+                self.zip_with(rhs, I::$op)
+            }
+        }
+
+        impl<'a, I $(, const $N: usize)?> $Op for &'a $Vec<I $(, $N)?>
+        where
+            &'a I: $Op<Output = I>,
+        {
+            type Output = $Vec<I $(, $N)?>;
+            fn $op(self, rhs: Self) -> Self::Output {
+                self.each_ref().zip_with(rhs.each_ref(), $Op::$op)
+            }
+        }
+
+        impl<I: $OpAssign $(, const $N: usize)?> $OpAssign for $Vec<I $(, $N)?> {
+            fn $op_assign(&mut self, rhs: Self) {
+                self.each_mut().zip_with(rhs, I::$op_assign);
+            }
+        }
+
+        impl<'a, I: $OpAssign<&'a I> $(, const $N: usize)?> $OpAssign<&'a Self>
+            for $Vec<I $(, $N)?>
+        {
+            fn $op_assign(&mut self, rhs: &'a Self) {
+                self.each_mut().zip_with(rhs.each_ref(), I::$op_assign);
+            }
+        }
+    };
+
+    // Operators between a vector on the left and a scalar on the right
+    (@scalar $Vec:ident $(, $N:ident)?; $Op:ident $op:ident $OpAssign:ident $op_assign:ident) => {
+        impl<I: $Op<Output = I> + Clone $(, const $N: usize)?> $Op<I> for $Vec<I $(, $N)?> {
+            type Output = Self;
+            fn $op(self, rhs: I) -> Self::Output {
+                self.apply(|component| component.$op(rhs.clone()))
+            }
+        }
+
+        impl<I: $OpAssign + Clone $(, const $N: usize)?> $OpAssign<I> for $Vec<I $(, $N)?> {
+            fn $op_assign(&mut self, rhs: I) {
+                self.each_mut().apply(|component| component.$op_assign(rhs.clone()));
+            }
+        }
+    };
+
+    // Multiplication by a primitive scalar on the left
+    (@scalar_lhs $Vec:ident $(, $N:ident)?;) => {};
+    (@scalar_lhs $Vec:ident $(, $N:ident)?; $T:ty $(, $rest:ty)*) => {
+        impl$(<const $N: usize>)? Mul<$Vec<$T $(, $N)?>> for $T {
+            type Output = $Vec<$T $(, $N)?>;
+            /// Multiply the vector $\vec v$ by this scalar $k$, yielding $k\vec v$.
+            fn mul(self, rhs: Self::Output) -> Self::Output {
+                rhs * self
+            }
+        }
+
+        impl_ops!(@scalar_lhs $Vec $(, $N)?; $($rest),*);
+    };
+}
+
+impl_ops!(Vec2D);
+impl_ops!(Vec3D);
+impl_ops!(Vec4D);
+impl_ops!(VecN, N);
@@ -0,0 +1,264 @@
+//! Iteration over the lattice points between two corners
+//!
+//! [`Vec2D::range`] and [`Vec3D::range`] iterate over every point with integer
+//! components in the box from one corner up to, but not including, another,
+//! like a [`Range`](std::ops::Range) does for a single number.
+//! [`Vec2D::range_inclusive`] and [`Vec3D::range_inclusive`] also include the
+//! points on the far sides of the box. The box is empty if the start is not
+//! before the end along every axis.
+//!
+//! The points are visited in row-major [`Order`] unless another order is given
+//! with `with_order`, and `step_by_axis` visits only every $n$th point along
+//! each axis.
+//!
+//! Ranges are only available for primitive integer components, and store the
+//! corners as [`i128`]s, so components which do not fit in an [`i128`] (such
+//! as [`u128`]s above [`i128::MAX`]) are not supported.
+//!
+//! ```
+//! use handyman::math::vector::{Order, Vec2D, Vec3D};
+//! let points = Vec2D::range(Vec2D(0, 0), Vec2D(3, 2));
+//! assert_eq!(points.len(), 6);
+//! assert_eq!(points.collect::<Vec<_>>(), [
+//!     Vec2D(0, 0),
+//!     Vec2D(1, 0),
+//!     Vec2D(2, 0),
+//!     Vec2D(0, 1),
+//!     Vec2D(1, 1),
+//!     Vec2D(2, 1)
+//! ]);
+//!
+//! let columns = Vec2D::range_inclusive(Vec2D(0, 0), Vec2D(1, 1)).with_order(Order::ColumnMajor);
+//! assert_eq!(columns.rev().collect::<Vec<_>>(), [
+//!     Vec2D(1, 1),
+//!     Vec2D(1, 0),
+//!     Vec2D(0, 1),
+//!     Vec2D(0, 0)
+//! ]);
+//!
+//! let cube = Vec3D::range_inclusive(Vec3D(0, 0, 0), Vec3D(4, 4, 4));
+//! let corners = cube.step_by_axis(Vec3D(4, 4, 4));
+//! assert_eq!(corners.len(), 8);
+//! assert_eq!(corners.last(), Some(Vec3D(4, 4, 4)));
+//! assert_eq!(Vec2D::range(Vec2D(0, 5), Vec2D(3, 5)).next(), None);
+//! assert_eq!(Vec2D::range(Vec2D(0, 0), Vec2D(i128::MIN, 5)).len(), 0);
+//! ```
+
+use std::{array, iter::FusedIterator, marker::PhantomData};
+
+use num::{NumCast, PrimInt};
+
+use super::{narrow, widen, Vec2D, Vec3D};
+
+/// The order in which a range visits its points
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Order {
+    /// Vary the $x$ component fastest and the last component slowest, visiting
+    /// each row of a grid in turn
+    #[default]
+    RowMajor,
+    /// Vary the last component fastest and the $x$ component slowest, visiting
+    /// each column of a grid in turn
+    ColumnMajor,
+}
+
+/// Implement a range `$Range` over the lattice points between two corners of
+/// the vector type `$Vec` with `$n` components
+macro_rules! impl_range {
+    ($Range:ident, $Vec:ident, $n:literal) => {
+        #[doc = concat!(
+            "An iterator over the lattice points in the box between two corners, see [`",
+            stringify!($Vec), "::range`]"
+        )]
+        ///
+        /// The corners are stored as [`i128`]s, so components which do not fit
+        /// in an [`i128`], such as [`u128`]s above [`i128::MAX`], are not
+        /// supported.
+        #[derive(Debug, Clone)]
+        pub struct $Range<I> {
+            /// The first point along each axis
+            start: [i128; $n],
+            /// The last point along each axis, which is before `start` along
+            /// some axis if the range is empty
+            last: [i128; $n],
+            /// The positive distance between consecutive points along each axis
+            step: [i128; $n],
+            /// The number of points along each axis
+            counts: [usize; $n],
+            /// The order in which the points are visited
+            order: Order,
+            /// The index of the next point to yield from the front
+            front: usize,
+            /// The index just after the next point to yield from the back
+            back: usize,
+            /// The component type of the points
+            component: PhantomData<fn() -> I>,
+        }
+
+        impl<I> $Range<I> {
+            /// Create a range visiting every point from `start` to `last`
+            /// inclusive, `step` apart along each axis
+            ///
+            /// # Panics
+            ///
+            /// Panics if the number of points does not fit in a [`usize`].
+            fn new(start: [i128; $n], last: [i128; $n], step: [i128; $n], order: Order) -> Self {
+                let counts: [usize; $n] = array::from_fn(|axis| {
+                    if last[axis] < start[axis] {
+                        return 0;
+                    }
+                    last[axis]
+                        .checked_sub(start[axis])
+                        .and_then(|distance| usize::try_from(distance / step[axis] + 1).ok())
+                        .expect("the number of points should fit in a usize")
+                });
+                let len = if counts.contains(&0) {
+                    0
+                } else {
+                    counts
+                        .iter()
+                        .try_fold(1_usize, |len, &count| len.checked_mul(count))
+                        .expect("the number of points should fit in a usize")
+                };
+                Self {
+                    start,
+                    last,
+                    step,
+                    counts,
+                    order,
+                    front: 0,
+                    back: len,
+                    component: PhantomData,
+                }
+            }
+
+            /// Visit the points in the given order, restarting the range
+            #[must_use]
+            pub fn with_order(self, order: Order) -> Self {
+                Self::new(self.start, self.last, self.step, order)
+            }
+        }
+
+        impl<I: NumCast> $Range<I> {
+            /// Visit only every `step`th point along each axis, starting from
+            /// the first point, and restart the range
+            ///
+            /// Unlike [`Iterator::step_by`], which skips over the sequence of
+            /// points, this skips along each axis separately, so the points
+            /// visited still form a box.
+            ///
+            /// # Panics
+            ///
+            /// Panics if any component of `step` is not positive.
+            #[must_use]
+            pub fn step_by_axis(self, step: $Vec<I>) -> Self {
+                let step = <[I; $n]>::from(step).map(widen);
+                assert!(
+                    step.iter().all(|&step| step > 0),
+                    "every component of the step should be positive"
+                );
+                Self::new(self.start, self.last, step, self.order)
+            }
+
+            /// The point at an index in the order of this range
+            fn point(&self, index: usize) -> $Vec<I> {
+                let axes: [usize; $n] = array::from_fn(|axis| match self.order {
+                    Order::RowMajor => axis,
+                    Order::ColumnMajor => $n - 1 - axis,
+                });
+                let mut rest = index;
+                let mut point = self.start;
+                for axis in axes {
+                    let offset = i128::try_from(rest % self.counts[axis])
+                        .unwrap_or_else(|_| unreachable!("every count fits in an i128"));
+                    point[axis] += offset * self.step[axis];
+                    rest /= self.counts[axis];
+                }
+                $Vec::from(point.map(narrow))
+            }
+        }
+
+        impl<I: PrimInt> $Vec<I> {
+            #[doc = concat!(
+                "Iterate over the lattice points in the box from `start` up to, but not ",
+                "including, `end`, see [`", stringify!($Range), "`]"
+            )]
+            ///
+            /// # Panics
+            ///
+            /// Panics if a component of the corners does not fit in an
+            /// [`i128`], or if the number of points does not fit in a
+            /// [`usize`].
+            #[must_use]
+            pub fn range(start: Self, end: Self) -> $Range<I> {
+                let start = <[I; $n]>::from(start).map(widen);
+                let end = <[I; $n]>::from(end).map(widen);
+                match end.try_map(|component| component.checked_sub(1)) {
+                    Some(last) => $Range::new(start, last, [1; $n], Order::RowMajor),
+                    // Nothing lies before an end of i128::MIN
+                    None => $Range::new([0; $n], [-1; $n], [1; $n], Order::RowMajor),
+                }
+            }
+
+            #[doc = concat!(
+                "Iterate over the lattice points in the box from `start` up to and including ",
+                "`end`, see [`", stringify!($Range), "`]"
+            )]
+            ///
+            /// # Panics
+            ///
+            /// Panics if a component of the corners does not fit in an
+            /// [`i128`], or if the number of points does not fit in a
+            /// [`usize`].
+            #[must_use]
+            pub fn range_inclusive(start: Self, end: Self) -> $Range<I> {
+                let start = <[I; $n]>::from(start).map(widen);
+                let last = <[I; $n]>::from(end).map(widen);
+                $Range::new(start, last, [1; $n], Order::RowMajor)
+            }
+        }
+
+        impl<I: NumCast> Iterator for $Range<I> {
+            type Item = $Vec<I>;
+            fn next(&mut self) -> Option<Self::Item> {
+                if self.front == self.back {
+                    return None;
+                }
+                self.front += 1;
+                Some(self.point(self.front - 1))
+            }
+
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                let len = self.back - self.front;
+                (len, Some(len))
+            }
+
+            fn nth(&mut self, n: usize) -> Option<Self::Item> {
+                self.front = self.front.saturating_add(n).min(self.back);
+                self.next()
+            }
+        }
+
+        impl<I: NumCast> DoubleEndedIterator for $Range<I> {
+            fn next_back(&mut self) -> Option<Self::Item> {
+                if self.front == self.back {
+                    return None;
+                }
+                self.back -= 1;
+                Some(self.point(self.back))
+            }
+
+            fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+                self.back = self.back.saturating_sub(n).max(self.front);
+                self.next_back()
+            }
+        }
+
+        impl<I: NumCast> ExactSizeIterator for $Range<I> {}
+
+        impl<I: NumCast> FusedIterator for $Range<I> {}
+    };
+}
+
+impl_range!(Range2D, Vec2D, 2);
+impl_range!(Range3D, Vec3D, 3);
@@ -0,0 +1,165 @@
+//! The rotations of the cube
+//!
+//! This module provides [`Rotation3`], the group of the 24 rotations which map
+//! the integer lattice onto itself while fixing the origin.
+
+use num::Signed;
+
+use super::Vec3D;
+use crate::math::matrix::Mat3;
+
+/// The permutations of three axes, along with whether each is even
+const PERMUTATIONS: [([usize; 3], bool); 6] = [
+    ([0, 1, 2], true),
+    ([1, 2, 0], true),
+    ([2, 0, 1], true),
+    ([0, 2, 1], false),
+    ([2, 1, 0], false),
+    ([1, 0, 2], false),
+];
+
+/// One of the 24 rotations of the cube, which can be applied to a [`Vec3D`]
+///
+/// Each rotation is a signed permutation matrix with determinant $1$: every
+/// component of the rotated vector is a component of the original vector,
+/// possibly negated. Quarter turns follow the right-hand rule, e.g.
+/// [`Rotation3::QUARTER_Z`] rotates the $x$ axis onto the $y$ axis.
+///
+/// ```
+/// use handyman::math::vector::{Rotation3, Vec3D};
+/// assert_eq!(Rotation3::QUARTER_Z.apply(Vec3D(1, 2, 3)), Vec3D(-2, 1, 3));
+/// assert_eq!(Rotation3::all().count(), 24);
+///
+/// // Find the rotation which maps one scan onto another
+/// let scan = [Vec3D(1, 2, 3), Vec3D(-4, 5, 0)];
+/// let rotated = scan.map(|point| Vec3D(point.2, -point.0, -point.1));
+/// let rotation = Rotation3::all()
+///     .find(|rotation| scan.map(|point| rotation.apply(point)) == rotated)
+///     .unwrap();
+/// assert_eq!(rotation.inverse().apply(rotated[1]), scan[1]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rotation3 {
+    /// The component of the original vector which becomes each component of
+    /// the rotated vector
+    axes: [usize; 3],
+    /// Whether each component of the rotated vector is negated
+    negated: [bool; 3],
+}
+
+impl Rotation3 {
+    /// The rotation which leaves every vector unchanged
+    pub const IDENTITY: Self = Self {
+        axes: [0, 1, 2],
+        negated: [false; 3],
+    };
+
+    /// A quarter turn about the $x$ axis, mapping
+    /// $\left[\begin{matrix}x&y&z\end{matrix}\right]$ to
+    /// $\left[\begin{matrix}x&-z&y\end{matrix}\right]$
+    pub const QUARTER_X: Self = Self {
+        axes: [0, 2, 1],
+        negated: [false, true, false],
+    };
+
+    /// A quarter turn about the $y$ axis, mapping
+    /// $\left[\begin{matrix}x&y&z\end{matrix}\right]$ to
+    /// $\left[\begin{matrix}z&y&-x\end{matrix}\right]$
+    pub const QUARTER_Y: Self = Self {
+        axes: [2, 1, 0],
+        negated: [false, false, true],
+    };
+
+    /// A quarter turn about the $z$ axis, mapping
+    /// $\left[\begin{matrix}x&y&z\end{matrix}\right]$ to
+    /// $\left[\begin{matrix}-y&x&z\end{matrix}\right]$
+    pub const QUARTER_Z: Self = Self {
+        axes: [1, 0, 2],
+        negated: [true, false, false],
+    };
+
+    /// Iterate over all 24 rotations, starting with [`Rotation3::IDENTITY`]
+    pub fn all() -> impl Iterator<Item = Self> {
+        PERMUTATIONS.into_iter().flat_map(|(axes, even)| {
+            (0..8_u8).filter_map(move |signs| {
+                let negated = [signs & 1 != 0, signs & 2 != 0, signs & 4 != 0];
+                // The determinant is the sign of the permutation times the
+                // product of the signs, which must be positive
+                let odd_negations = negated.into_iter().filter(|&negated| negated).count() % 2 == 1;
+                (even != odd_negations).then_some(Self { axes, negated })
+            })
+        })
+    }
+
+    /// Compose two rotations, yielding the rotation which applies `self` and
+    /// then `next`
+    ///
+    /// ```
+    /// use handyman::math::vector::Rotation3;
+    /// let half_turn = Rotation3::QUARTER_X.then(Rotation3::QUARTER_X);
+    /// assert_eq!(half_turn.then(half_turn), Rotation3::IDENTITY);
+    /// ```
+    #[must_use]
+    pub const fn then(self, next: Self) -> Self {
+        let mut axes = [0; 3];
+        let mut negated = [false; 3];
+        let mut index = 0;
+        while index < 3 {
+            let source = next.axes[index];
+            axes[index] = self.axes[source];
+            negated[index] = next.negated[index] != self.negated[source];
+            index += 1;
+        }
+        Self { axes, negated }
+    }
+
+    /// The inverse of this rotation, which undoes it
+    #[must_use]
+    pub const fn inverse(self) -> Self {
+        let mut axes = [0; 3];
+        let mut negated = [false; 3];
+        let mut index = 0;
+        while index < 3 {
+            axes[self.axes[index]] = index;
+            negated[self.axes[index]] = self.negated[index];
+            index += 1;
+        }
+        Self { axes, negated }
+    }
+
+    /// Apply this rotation to a vector
+    pub fn apply<I: Signed>(self, vector: Vec3D<I>) -> Vec3D<I> {
+        let mut components = <[I; 3]>::from(vector).map(Some);
+        Vec3D::from_fn(|axis| {
+            let component = components[self.axes[axis]]
+                .take()
+                .unwrap_or_else(|| unreachable!("each component is moved exactly once"));
+            if self.negated[axis] {
+                -component
+            } else {
+                component
+            }
+        })
+    }
+
+    /// Obtain the signed permutation matrix of this rotation
+    ///
+    /// ```
+    /// use handyman::math::vector::{Rotation3, Vec3D};
+    /// let v = Vec3D(1, 2, 3);
+    /// assert_eq!(
+    ///     Rotation3::QUARTER_Y.to_matrix() * v,
+    ///     Rotation3::QUARTER_Y.apply(v)
+    /// );
+    /// ```
+    #[must_use]
+    pub fn to_matrix<I: Signed>(self) -> Mat3<I> {
+        Mat3::from_fn(
+            |row, col| match (self.axes[row] == col, self.negated[row]) {
+                (false, _) => I::zero(),
+                (true, false) => I::one(),
+                (true, true) => -I::one(),
+            },
+        )
+    }
+}
@@ -0,0 +1,196 @@
+//! The symmetries of the square
+//!
+//! This module provides [`D4`], the dihedral group of the 8 rotations and
+//! reflections which map the integer lattice onto itself while fixing the
+//! origin.
+
+use std::array;
+
+use num::Signed;
+
+use super::Vec2D;
+
+/// One of the 8 symmetries of the square, which can be applied to a [`Vec2D`]
+///
+/// Rotations are named assuming the $y$ axis points up; if it points down, as
+/// is usual for grids, [`D4::RotateCcw`] and [`D4::RotateCw`] swap meanings.
+///
+/// ```
+/// use handyman::math::vector::{Vec2D, D4};
+/// assert_eq!(D4::RotateCw.apply(Vec2D(1, 2)), Vec2D(2, -1));
+/// assert_eq!(D4::FlipX.then(D4::RotateCcw), D4::AntiTranspose);
+/// assert_eq!(D4::RotateCw.inverse(), D4::RotateCcw);
+/// assert!(D4::ALL
+///     .into_iter()
+///     .all(|symmetry| symmetry.then(symmetry.inverse()) == D4::Identity));
+///
+/// // The canonical form of a shape under rotation and reflection, here the
+/// // smallest sorted list of its cells translated to touch both axes
+/// fn canonical(shape: &[Vec2D<i32>]) -> Vec<Vec2D<i32>> {
+///     D4::images(shape)
+///         .map(|mut image| {
+///             let min_x = image.iter().map(|point| point.0).min().unwrap_or(0);
+///             let min_y = image.iter().map(|point| point.1).min().unwrap_or(0);
+///             for point in &mut image {
+///                 *point -= Vec2D(min_x, min_y);
+///             }
+///             image.sort_by_key(|&Vec2D(x, y)| (x, y));
+///             image
+///         })
+///         .min_by_key(|image| image.iter().map(|&Vec2D(x, y)| (x, y)).collect::<Vec<_>>())
+///         .unwrap_or_default()
+/// }
+/// let l = [Vec2D(0, 0), Vec2D(0, 1), Vec2D(0, 2), Vec2D(1, 0)];
+/// let j = [Vec2D(0, 0), Vec2D(1, 0), Vec2D(1, 1), Vec2D(1, 2)];
+/// assert_eq!(canonical(&l), canonical(&j));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum D4 {
+    /// Leave the vector unchanged
+    Identity,
+    /// Rotate a quarter turn counter-clockwise, see [`Vec2D::rotate_ccw`]
+    RotateCcw,
+    /// Rotate a half turn, see [`Vec2D::rotate_180`]
+    Rotate180,
+    /// Rotate a quarter turn clockwise, see [`Vec2D::rotate_cw`]
+    RotateCw,
+    /// Reflect across the $y$ axis, see [`Vec2D::flip_x`]
+    FlipX,
+    /// Reflect across the $x$ axis, see [`Vec2D::flip_y`]
+    FlipY,
+    /// Reflect across the line $y=x$, see [`Vec2D::transpose`]
+    Transpose,
+    /// Reflect across the line $y=-x$
+    AntiTranspose,
+}
+
+impl D4 {
+    /// Every symmetry, starting with the rotations
+    pub const ALL: [Self; 8] = [
+        Self::Identity,
+        Self::RotateCcw,
+        Self::Rotate180,
+        Self::RotateCw,
+        Self::FlipX,
+        Self::AntiTranspose,
+        Self::FlipY,
+        Self::Transpose,
+    ];
+
+    /// Decompose this symmetry into a number of counter-clockwise quarter
+    /// turns, applied after an optional [`D4::FlipX`]
+    const fn to_parts(self) -> (u8, bool) {
+        match self {
+            Self::Identity => (0, false),
+            Self::RotateCcw => (1, false),
+            Self::Rotate180 => (2, false),
+            Self::RotateCw => (3, false),
+            Self::FlipX => (0, true),
+            Self::AntiTranspose => (1, true),
+            Self::FlipY => (2, true),
+            Self::Transpose => (3, true),
+        }
+    }
+
+    /// Compose a symmetry from a number of counter-clockwise quarter turns,
+    /// applied after an optional [`D4::FlipX`]
+    const fn from_parts(quarter_turns: u8, flipped: bool) -> Self {
+        match (quarter_turns % 4, flipped) {
+            (0, false) => Self::Identity,
+            (1, false) => Self::RotateCcw,
+            (2, false) => Self::Rotate180,
+            (3, false) => Self::RotateCw,
+            (0, true) => Self::FlipX,
+            (1, true) => Self::AntiTranspose,
+            (2, true) => Self::FlipY,
+            _ => Self::Transpose,
+        }
+    }
+
+    /// Whether this symmetry is a reflection rather than a rotation
+    #[must_use]
+    pub const fn is_reflection(self) -> bool {
+        self.to_parts().1
+    }
+
+    /// Compose two symmetries, yielding the symmetry which applies `self` and
+    /// then `next`
+    #[must_use]
+    pub const fn then(self, next: Self) -> Self {
+        let (turns, flipped) = self.to_parts();
+        let (next_turns, next_flipped) = next.to_parts();
+        // Reflecting reverses the direction of the quarter turns before it
+        let turns = if next_flipped { 4 - turns } else { turns };
+        Self::from_parts(turns + next_turns, flipped != next_flipped)
+    }
+
+    /// The inverse of this symmetry, which undoes it
+    #[must_use]
+    pub const fn inverse(self) -> Self {
+        match self {
+            Self::RotateCcw => Self::RotateCw,
+            Self::RotateCw => Self::RotateCcw,
+            Self::Identity
+            | Self::Rotate180
+            | Self::FlipX
+            | Self::FlipY
+            | Self::Transpose
+            | Self::AntiTranspose => self,
+        }
+    }
+
+    /// Apply this symmetry to a vector
+    pub fn apply<I: Signed>(self, vector: Vec2D<I>) -> Vec2D<I> {
+        match self {
+            Self::Identity => vector,
+            Self::RotateCcw => vector.rotate_ccw(),
+            Self::Rotate180 => vector.rotate_180(),
+            Self::RotateCw => vector.rotate_cw(),
+            Self::FlipX => vector.flip_x(),
+            Self::FlipY => vector.flip_y(),
+            Self::Transpose => vector.transpose(),
+            Self::AntiTranspose => vector.transpose().rotate_180(),
+        }
+    }
+
+    /// Iterate over the images of a set of points under every symmetry, in the
+    /// order of [`D4::ALL`]
+    pub fn images<I: Signed + Clone>(points: &[Vec2D<I>]) -> array::IntoIter<Vec<Vec2D<I>>, 8> {
+        Self::ALL
+            .map(|symmetry| {
+                points
+                    .iter()
+                    .cloned()
+                    .map(|point| symmetry.apply(point))
+                    .collect()
+            })
+            .into_iter()
+    }
+}
+
+impl<I: Signed + Clone> Vec2D<I> {
+    /// Iterate over the images of this vector under every symmetry of the
+    /// square, in the order of [`D4::ALL`]
+    ///
+    /// ```
+    /// use handyman::math::vector::Vec2D;
+    /// assert_eq!(
+    ///     Vec2D(1, 2).images().collect::<Vec<_>>(),
+    ///     [
+    ///         Vec2D(1, 2),
+    ///         Vec2D(-2, 1),
+    ///         Vec2D(-1, -2),
+    ///         Vec2D(2, -1),
+    ///         Vec2D(-1, 2),
+    ///         Vec2D(-2, -1),
+    ///         Vec2D(1, -2),
+    ///         Vec2D(2, 1),
+    ///     ]
+    /// );
+    /// ```
+    pub fn images(self) -> array::IntoIter<Self, 8> {
+        D4::ALL
+            .map(|symmetry| symmetry.apply(self.clone()))
+            .into_iter()
+    }
+}
@@ -1,14 +1,31 @@
 //! 2-dimensional vectors
 //!
 //! This module provides [`Vec2D`].
+//!
+//! A [`Vec2D`] $\left[\begin{matrix}x&y\end{matrix}\right]$ can be treated as
+//! the complex number $x+yi$, and converted to and from [`num::Complex`] with
+//! [`From`]/[`Into`]. Complex multiplication rotates and scales points, which
+//! is often simpler than using an angle:
+//!
+//! ```
+//! use handyman::math::vector::Vec2D;
+//! use num::Complex;
+//! assert_eq!(Complex::from(Vec2D(1, 2)), Complex::new(1, 2));
+//! assert_eq!(Vec2D::from(Complex::new(1, 2)), Vec2D(1, 2));
+//!
+//! // Multiplying by 2i rotates a quarter turn counter-clockwise and doubles
+//! assert_eq!(Vec2D(3, 1).complex_mul(Vec2D(0, 2)), Vec2D(-2, 6));
+//! assert_eq!(Vec2D(-2, 6).complex_div(Vec2D(0, 2)), Some(Vec2D(3, 1)));
+//! assert_eq!(Vec2D(3, 1).conj(), Vec2D(3, -1));
+//! ```
 
-use std::ops::{Add, Mul, Neg};
+use num::{Complex, Float, Num, Signed};
 
-use num::{Num, Signed};
+use super::{Vec3D, VecN};
 
 /// A two-dimensional vector $\left[\begin{matrix}x&y\end{matrix}\right]$ backed
 /// by an integer type `I`
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct Vec2D<I>(pub I, pub I);
 
 impl<I> Vec2D<I> {
@@ -16,17 +33,34 @@ impl<I> Vec2D<I> {
     /// tuple $(x, y)$.
     ///
     /// Using the [`Vec2D`] constructor directly (e.g. `Vec2D(1, 2)`) is
-    /// preferred, however if you already have a tuple, this is easier.
+    /// preferred, however if you already have a tuple, this is easier. The
+    /// same conversion is available through [`From`]/[`Into`].
     ///
     /// ```
     /// use handyman::math::vector::Vec2D;
-    /// assert_eq!(Vec2D::from_tuple(1, 3), Vec2D(1, 3));
+    /// assert_eq!(Vec2D::from_tuple((1, 3)), Vec2D(1, 3));
+    /// assert_eq!(Vec2D::from((1, 3)), Vec2D(1, 3));
+    /// assert_eq!(<(i32, i32)>::from(Vec2D(1, 3)), (1, 3));
     /// ```
     #[must_use]
     pub fn from_tuple((x, y): (I, I)) -> Self {
         Self(x, y)
     }
 
+    /// Create a [`Vec2D`] by calling a function $f$ with the index of each
+    /// component, i.e. $\left[\begin{matrix}f(0)&f(1)\end{matrix}\right]$.
+    ///
+    /// ```
+    /// use handyman::math::vector::Vec2D;
+    /// assert_eq!(Vec2D::from_fn(|i| i + 1), Vec2D(1, 2));
+    /// ```
+    pub fn from_fn<F>(mut f: F) -> Self
+    where
+        F: FnMut(usize) -> I,
+    {
+        Self(f(0), f(1))
+    }
+
     /// Obtain the $x$ component from a vector
     /// $\left[\begin{matrix}x&y\end{matrix}\right]$
     ///
@@ -51,6 +85,29 @@ impl<I> Vec2D<I> {
         self.1
     }
 
+    /// Borrow each component of this vector, yielding a vector of references
+    ///
+    /// ```
+    /// use handyman::math::vector::Vec2D;
+    /// assert_eq!(Vec2D(1, 2).each_ref(), Vec2D(&1, &2));
+    /// ```
+    pub const fn each_ref(&self) -> Vec2D<&I> {
+        Vec2D(&self.0, &self.1)
+    }
+
+    /// Mutably borrow each component of this vector, yielding a vector of
+    /// mutable references
+    ///
+    /// ```
+    /// use handyman::math::vector::Vec2D;
+    /// let mut v = Vec2D(1, 2);
+    /// *v.each_mut().1 = 5;
+    /// assert_eq!(v, Vec2D(1, 5));
+    /// ```
+    pub const fn each_mut(&mut self) -> Vec2D<&mut I> {
+        Vec2D(&mut self.0, &mut self.1)
+    }
+
     /// Apply a function $f$ onto both components of this vector
     ///
     /// For a vector $\vec v=\left[\begin{matrix}v_x&v_y\end{matrix}\right]$,
@@ -76,7 +133,7 @@ impl<I> Vec2D<I> {
     /// function $f(a, b)$, `a.zip_with(b, f)` will yield the vector $\vec
     /// c=\left[\begin{matrix}f(a_x, b_x)&f(a_y, b_y)\end{matrix}\right]$.
     ///
-    /// Example (trivial implementation of [`Vec2D::add`]):
+    /// Example (trivial implementation of [`Add`](std::ops::Add)):
     /// ```
     /// use handyman::math::vector::Vec2D;
     /// assert_eq!(Vec2D(1, 2).zip_with(Vec2D(3, 4), |a, b| a + b), Vec2D(4, 6));
@@ -87,57 +144,268 @@ impl<I> Vec2D<I> {
     {
         Vec2D(f(self.0, other.0), f(self.1, other.1))
     }
+
+    /// Swap the components of this vector, yielding
+    /// $\left[\begin{matrix}y&x\end{matrix}\right]$, i.e. reflect it across the
+    /// line $y=x$
+    ///
+    /// ```
+    /// use handyman::math::vector::Vec2D;
+    /// assert_eq!(Vec2D(1, 2).transpose(), Vec2D(2, 1));
+    /// ```
+    #[must_use]
+    pub fn transpose(self) -> Self {
+        Self(self.1, self.0)
+    }
 }
 
-impl<I: Num> Vec2D<I> {
-    /// The additive identity vector
-    /// $\left[\begin{matrix}0&0\end{matrix}\right]$
+impl<I: Signed> Vec2D<I> {
+    /// Compute the perpendicular dot product (the scalar 2D cross product)
+    /// $\vec a^\perp\cdot\vec b=a_xb_y-a_yb_x$ of two vectors
+    ///
+    /// This is positive when $\vec b$ is counter-clockwise from $\vec a$,
+    /// negative when it is clockwise, and zero when they are parallel.
+    ///
+    /// ```
+    /// use handyman::math::vector::Vec2D;
+    /// assert_eq!(Vec2D(1, 0).perp_dot(Vec2D(0, 1)), 1);
+    /// assert_eq!(Vec2D(0, 1).perp_dot(Vec2D(1, 0)), -1);
+    /// assert_eq!(Vec2D(2, 4).perp_dot(Vec2D(1, 2)), 0);
+    /// ```
+    #[must_use]
+    pub fn perp_dot(self, rhs: Self) -> I {
+        self.0 * rhs.1 - self.1 * rhs.0
+    }
+
+    /// Rotate this vector a quarter turn counter-clockwise, yielding
+    /// $\left[\begin{matrix}-y&x\end{matrix}\right]$
+    ///
+    /// This assumes the $y$ axis points up. If it points down, as is usual for
+    /// grids and screens, this rotates clockwise instead.
+    ///
+    /// ```
+    /// use handyman::math::vector::Vec2D;
+    /// assert_eq!(Vec2D(1, 2).rotate_ccw(), Vec2D(-2, 1));
+    /// ```
+    #[must_use]
+    pub fn rotate_ccw(self) -> Self {
+        Self(-self.1, self.0)
+    }
+
+    /// Rotate this vector a quarter turn clockwise, yielding
+    /// $\left[\begin{matrix}y&-x\end{matrix}\right]$
+    ///
+    /// This assumes the $y$ axis points up. If it points down, as is usual for
+    /// grids and screens, this rotates counter-clockwise instead.
+    ///
+    /// ```
+    /// use handyman::math::vector::Vec2D;
+    /// assert_eq!(Vec2D(1, 2).rotate_cw(), Vec2D(2, -1));
+    /// ```
+    #[must_use]
+    pub fn rotate_cw(self) -> Self {
+        Self(self.1, -self.0)
+    }
+
+    /// Rotate this vector a half turn, yielding
+    /// $\left[\begin{matrix}-x&-y\end{matrix}\right]$
+    ///
+    /// ```
+    /// use handyman::math::vector::Vec2D;
+    /// assert_eq!(Vec2D(1, 2).rotate_180(), Vec2D(-1, -2));
+    /// ```
+    #[must_use]
+    pub fn rotate_180(self) -> Self {
+        -self
+    }
+
+    /// Negate the $x$ component of this vector, yielding
+    /// $\left[\begin{matrix}-x&y\end{matrix}\right]$, i.e. reflect it across
+    /// the $y$ axis
+    ///
+    /// ```
+    /// use handyman::math::vector::Vec2D;
+    /// assert_eq!(Vec2D(1, 2).flip_x(), Vec2D(-1, 2));
+    /// ```
+    #[must_use]
+    pub fn flip_x(self) -> Self {
+        Self(-self.0, self.1)
+    }
+
+    /// Negate the $y$ component of this vector, yielding
+    /// $\left[\begin{matrix}x&-y\end{matrix}\right]$, i.e. reflect it across
+    /// the $x$ axis
+    ///
+    /// ```
+    /// use handyman::math::vector::Vec2D;
+    /// assert_eq!(Vec2D(1, 2).flip_y(), Vec2D(1, -2));
+    /// ```
     #[must_use]
-    pub fn zero() -> Self {
-        Self(I::zero(), I::zero())
+    pub fn flip_y(self) -> Self {
+        Self(self.0, -self.1)
     }
 
-    /// The multiplicative identity vector
-    /// $\left[\begin{matrix}1&1\end{matrix}\right]$
+    /// Compute the complex conjugate $x-yi$ of this vector treated as the
+    /// complex number $x+yi$, which is the same as [`Vec2D::flip_y`]
+    ///
+    /// ```
+    /// use handyman::math::vector::Vec2D;
+    /// assert_eq!(Vec2D(1, 2).conj(), Vec2D(1, -2));
+    /// ```
     #[must_use]
-    pub fn one() -> Self {
-        Self(I::one(), I::one())
+    pub fn conj(self) -> Self {
+        self.flip_y()
     }
 }
 
-impl<I: Num + Copy> Mul<I> for Vec2D<I> {
-    type Output = Self;
-    /// Multiply this vector $\vec v$ by a scalar $k$, yielding $k\vec v$.
+impl<I: Float> Vec2D<I> {
+    /// Rotate this vector counter-clockwise by `angle` radians, yielding
+    /// $\left[\begin{matrix}x\cos\theta-y\sin\theta&x\sin\theta+y\cos\theta
+    /// \end{matrix}\right]$
+    ///
+    /// ```
+    /// use std::f64::consts::PI;
+    ///
+    /// use handyman::math::vector::Vec2D;
+    /// let rotated = Vec2D(1.0, 0.0).rotate(PI / 2.0);
+    /// assert!(rotated.distance(Vec2D(0.0, 1.0)) < 1e-12);
+    /// ```
+    #[must_use]
+    pub fn rotate(self, angle: I) -> Self {
+        let (sin, cos) = angle.sin_cos();
+        Self(self.0 * cos - self.1 * sin, self.0 * sin + self.1 * cos)
+    }
+
+    /// Compute the argument of this vector treated as a complex number, the
+    /// counter-clockwise angle in radians from the $x$ axis to it, in
+    /// $[-\pi, \pi]$
     ///
     /// ```
+    /// use std::f64::consts::FRAC_PI_2;
+    ///
     /// use handyman::math::vector::Vec2D;
-    /// assert_eq!(Vec2D(1, 3) * 2, Vec2D(2, 6));
+    /// assert_eq!(Vec2D(0.0, 2.0).arg(), FRAC_PI_2);
     /// ```
-    fn mul(self, rhs: I) -> Self::Output {
-        self.apply(|x| x * rhs)
+    #[must_use]
+    pub fn arg(self) -> I {
+        self.1.atan2(self.0)
+    }
+
+    /// Create a [`Vec2D`] from polar coordinates, with a length (modulus) and
+    /// a counter-clockwise angle (argument) in radians from the $x$ axis
+    ///
+    /// ```
+    /// use std::f64::consts::FRAC_PI_2;
+    ///
+    /// use handyman::math::vector::Vec2D;
+    /// let v = Vec2D::from_polar(2.0, FRAC_PI_2);
+    /// assert!(v.distance(Vec2D(0.0, 2.0)) < 1e-12);
+    /// ```
+    #[must_use]
+    pub fn from_polar(length: I, angle: I) -> Self {
+        let (sin, cos) = angle.sin_cos();
+        Self(length * cos, length * sin)
     }
 }
-impl<I: Num + Copy + Signed> Neg for Vec2D<I> {
-    type Output = Self;
-    fn neg(self) -> Self::Output {
-        self * I::one().neg()
+
+impl<I: Num> Vec2D<I> {
+    /// Lift this point $\left[\begin{matrix}x&y\end{matrix}\right]$ into the
+    /// homogeneous coordinates $\left[\begin{matrix}x&y&1\end{matrix}\right]$
+    ///
+    /// ```
+    /// use handyman::math::vector::{Vec2D, Vec3D};
+    /// assert_eq!(Vec2D(1, 2).to_homogeneous(), Vec3D(1, 2, 1));
+    /// ```
+    #[must_use]
+    pub fn to_homogeneous(self) -> Vec3D<I> {
+        let Self(x, y) = self;
+        Vec3D(x, y, I::one())
     }
 }
 
-impl<I: Num + Copy> Add for Vec2D<I> {
-    type Output = Self;
-    /// Add two vectors.
+impl<I: Num + Clone> Vec2D<I> {
+    /// Project the homogeneous coordinates
+    /// $\left[\begin{matrix}x&y&w\end{matrix}\right]$ back into the point
+    /// $\left[\begin{matrix}\frac xw&\frac yw\end{matrix}\right]$
+    ///
+    /// Returns [`None`] if $w=0$, i.e. if the coordinates describe a point at
+    /// infinity. Note that for integer components the division truncates.
+    ///
+    /// ```
+    /// use handyman::math::vector::{Vec2D, Vec3D};
+    /// assert_eq!(Vec2D::from_homogeneous(Vec3D(3.0, 6.0, 3.0)), Some(Vec2D(1.0, 2.0)));
+    /// assert_eq!(Vec2D::from_homogeneous(Vec3D(3.0, 6.0, 0.0)), None);
+    /// ```
+    #[must_use]
+    pub fn from_homogeneous(Vec3D(x, y, w): Vec3D<I>) -> Option<Self> {
+        (!w.is_zero()).then(|| Self(x, y) / w)
+    }
+
+    /// Multiply two vectors treated as complex numbers, yielding
+    /// $\left[\begin{matrix}a_xb_x-a_yb_y&a_xb_y+a_yb_x\end{matrix}\right]$
     ///
-    /// Given the vectors $\vec
-    /// a=\left[\begin{matrix}a_x&a_y\end{matrix}\right]$ and $\vec
-    /// b=\left[\begin{matrix}b_x&b_y\end{matrix}\right]$, yielding a vector
-    /// $\vec c=\left[\begin{matrix}a_x+b_x&a_y+b_y\end{matrix}\right]$.
+    /// This rotates `self` by the argument of `rhs` and scales it by the
+    /// length of `rhs`.
     ///
     /// ```
     /// use handyman::math::vector::Vec2D;
-    /// assert_eq!(Vec2D(1, 2) + Vec2D(3, 4), Vec2D(4, 6));
+    /// assert_eq!(Vec2D(1, 2).complex_mul(Vec2D(3, 4)), Vec2D(-5, 10));
     /// ```
-    fn add(self, rhs: Self) -> Self::Output {
-        self.zip_with(rhs, I::add)
+    #[must_use]
+    pub fn complex_mul(self, rhs: Self) -> Self {
+        (Complex::from(self) * Complex::from(rhs)).into()
+    }
+
+    /// Divide two vectors treated as complex numbers, undoing
+    /// [`Vec2D::complex_mul`]
+    ///
+    /// Returns [`None`] if `rhs` is zero. Note that for integer components the
+    /// division truncates.
+    ///
+    /// ```
+    /// use handyman::math::vector::Vec2D;
+    /// assert_eq!(Vec2D(-5.0, 10.0).complex_div(Vec2D(3.0, 4.0)), Some(Vec2D(1.0, 2.0)));
+    /// assert_eq!(Vec2D(1.0, 2.0).complex_div(Vec2D(0.0, 0.0)), None);
+    /// ```
+    #[must_use]
+    pub fn complex_div(self, rhs: Self) -> Option<Self> {
+        (!(rhs.0.is_zero() && rhs.1.is_zero()))
+            .then(|| (Complex::from(self) / Complex::from(rhs)).into())
+    }
+}
+
+impl<I> From<VecN<I, 2>> for Vec2D<I> {
+    fn from(VecN([x, y]): VecN<I, 2>) -> Self {
+        Self(x, y)
+    }
+}
+
+impl<I> From<Vec2D<I>> for VecN<I, 2> {
+    fn from(Vec2D(x, y): Vec2D<I>) -> Self {
+        Self([x, y])
+    }
+}
+
+impl<I> From<(I, I)> for Vec2D<I> {
+    fn from(tuple: (I, I)) -> Self {
+        Self::from_tuple(tuple)
+    }
+}
+
+impl<I> From<Vec2D<I>> for (I, I) {
+    fn from(Vec2D(x, y): Vec2D<I>) -> Self {
+        (x, y)
+    }
+}
+
+impl<I> From<Complex<I>> for Vec2D<I> {
+    fn from(Complex { re, im }: Complex<I>) -> Self {
+        Self(re, im)
+    }
+}
+
+impl<I> From<Vec2D<I>> for Complex<I> {
+    fn from(Vec2D(x, y): Vec2D<I>) -> Self {
+        Self::new(x, y)
     }
 }
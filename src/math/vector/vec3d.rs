@@ -2,13 +2,13 @@
 //!
 //! This module provides [`Vec3D`].
 
-use std::ops::{Add, Mul, Neg};
-
 use num::{Num, Signed};
 
+use super::{Vec4D, VecN};
+
 /// A three-dimensional vector $\left[\begin{matrix}x&y&z\end{matrix}\right]$
 /// backed by an integer type `I`
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct Vec3D<I>(pub I, pub I, pub I);
 
 impl<I> Vec3D<I> {
@@ -20,13 +20,23 @@ impl<I> Vec3D<I> {
     ///
     /// ```
     /// use handyman::math::vector::Vec3D;
-    /// assert_eq!(Vec3D::from_tuple(1, 3, 5), Vec3D(1, 3, 5));
+    /// assert_eq!(Vec3D::from_tuple((1, 3, 5)), Vec3D(1, 3, 5));
     /// ```
     #[must_use]
     pub fn from_tuple((x, y, z): (I, I, I)) -> Self {
         Self(x, y, z)
     }
 
+    /// Create a [`Vec3D`] by calling a function $f$ with the index of each
+    /// component, i.e.
+    /// $\left[\begin{matrix}f(0)&f(1)&f(2)\end{matrix}\right]$.
+    pub fn from_fn<F>(mut f: F) -> Self
+    where
+        F: FnMut(usize) -> I,
+    {
+        Self(f(0), f(1), f(2))
+    }
+
     /// Obtain the $x$ component from a vector
     /// $\left[\begin{matrix}x&y&z\end{matrix}\right]$
     #[must_use]
@@ -41,14 +51,25 @@ impl<I> Vec3D<I> {
         self.1
     }
 
-    // Obtain the $z$ component from a vector
+    /// Obtain the $z$ component from a vector
     /// $\left[\begin{matrix}x&y&z\end{matrix}\right]$
     #[must_use]
     pub fn z(self) -> I {
         self.2
     }
 
-    /// Apply a function $f$ onto both components of this vector
+    /// Borrow each component of this vector, yielding a vector of references
+    pub const fn each_ref(&self) -> Vec3D<&I> {
+        Vec3D(&self.0, &self.1, &self.2)
+    }
+
+    /// Mutably borrow each component of this vector, yielding a vector of
+    /// mutable references
+    pub const fn each_mut(&mut self) -> Vec3D<&mut I> {
+        Vec3D(&mut self.0, &mut self.1, &mut self.2)
+    }
+
+    /// Apply a function $f$ onto every component of this vector
     ///
     /// For a vector $\vec
     /// v=\left[\begin{matrix}v_x&v_y&v_z\end{matrix}\right]$, `v.apply(f)`
@@ -77,45 +98,82 @@ impl<I> Vec3D<I> {
     }
 }
 
+impl<I: Signed + Clone> Vec3D<I> {
+    /// Compute the cross product $\vec a\times\vec b$ of two vectors
+    ///
+    /// ```
+    /// use handyman::math::vector::Vec3D;
+    /// assert_eq!(Vec3D(1, 0, 0).cross(Vec3D(0, 1, 0)), Vec3D(0, 0, 1));
+    /// assert_eq!(Vec3D(1, 2, 3).cross(Vec3D(4, 5, 6)), Vec3D(-3, 6, -3));
+    /// ```
+    #[must_use]
+    pub fn cross(self, rhs: Self) -> Self {
+        let Self(ax, ay, az) = self;
+        let Self(bx, by, bz) = rhs;
+        Self(
+            ay.clone() * bz.clone() - az.clone() * by.clone(),
+            az * bx.clone() - ax.clone() * bz,
+            ax * by - ay * bx,
+        )
+    }
+}
+
 impl<I: Num> Vec3D<I> {
-    /// The additive identity vector
-    /// $\left[\begin{matrix}0&0&0\end{matrix}\right]$
+    /// Lift this point $\left[\begin{matrix}x&y&z\end{matrix}\right]$ into
+    /// the homogeneous coordinates
+    /// $\left[\begin{matrix}x&y&z&1\end{matrix}\right]$
+    ///
+    /// ```
+    /// use handyman::math::vector::{Vec3D, Vec4D};
+    /// assert_eq!(Vec3D(1, 2, 3).to_homogeneous(), Vec4D(1, 2, 3, 1));
+    /// ```
     #[must_use]
-    pub fn zero() -> Self {
-        Self(I::zero(), I::zero(), I::zero())
+    pub fn to_homogeneous(self) -> Vec4D<I> {
+        let Self(x, y, z) = self;
+        Vec4D(x, y, z, I::one())
     }
+}
 
-    /// The multiplicative identity vector
-    /// $\left[\begin{matrix}1&1&1\end{matrix}\right]$
+impl<I: Num + Clone> Vec3D<I> {
+    /// Project the homogeneous coordinates
+    /// $\left[\begin{matrix}x&y&z&w\end{matrix}\right]$ back into the point
+    /// $\left[\begin{matrix}\frac xw&\frac yw&\frac zw\end{matrix}\right]$
+    ///
+    /// Returns [`None`] if $w=0$, i.e. if the coordinates describe a point at
+    /// infinity. Note that for integer components the division truncates. This
+    /// can also be written as [`Vec4D::from_homogeneous`].
+    ///
+    /// ```
+    /// use handyman::math::vector::{Vec3D, Vec4D};
+    /// assert_eq!(Vec3D::from_homogeneous(Vec4D(2, 4, 6, 2)), Some(Vec3D(1, 2, 3)));
+    /// assert_eq!(Vec3D::from_homogeneous(Vec4D(2, 4, 6, 0)), None);
+    /// ```
     #[must_use]
-    pub fn one() -> Self {
-        Self(I::one(), I::one(), I::one())
+    pub fn from_homogeneous(Vec4D(x, y, z, w): Vec4D<I>) -> Option<Self> {
+        (!w.is_zero()).then(|| Self(x, y, z) / w)
     }
 }
 
-impl<I: Num + Copy> Mul<I> for Vec3D<I> {
-    type Output = Self;
-    /// Multiply this vector $\vec v$ by a scalar $k$, yielding $k\vec v$.
-    fn mul(self, rhs: I) -> Self::Output {
-        self.apply(|x| x * rhs)
+impl<I> From<VecN<I, 3>> for Vec3D<I> {
+    fn from(VecN([x, y, z]): VecN<I, 3>) -> Self {
+        Self(x, y, z)
     }
 }
-impl<I: Num + Copy + Signed> Neg for Vec3D<I> {
-    type Output = Self;
-    fn neg(self) -> Self::Output {
-        self * I::one().neg()
+
+impl<I> From<Vec3D<I>> for VecN<I, 3> {
+    fn from(Vec3D(x, y, z): Vec3D<I>) -> Self {
+        Self([x, y, z])
     }
 }
 
-impl<I: Num + Copy> Add for Vec3D<I> {
-    type Output = Self;
-    /// Add two vectors.
-    ///
-    /// Given the vectors $\vec
-    /// a=\left[\begin{matrix}a_x&a_y&a_z\end{matrix}\right]$ and $\vec
-    /// b=\left[\begin{matrix}b_x&b_y&a_z\end{matrix}\right]$, yielding a vector
-    /// $\vec c=\left[\begin{matrix}a_x+b_x&a_y+b_y&a_z+b_z\end{matrix}\right]$.
-    fn add(self, rhs: Self) -> Self::Output {
-        self.zip_with(rhs, I::add)
+impl<I> From<(I, I, I)> for Vec3D<I> {
+    fn from(tuple: (I, I, I)) -> Self {
+        Self::from_tuple(tuple)
+    }
+}
+
+impl<I> From<Vec3D<I>> for (I, I, I) {
+    fn from(Vec3D(x, y, z): Vec3D<I>) -> Self {
+        (x, y, z)
     }
 }
@@ -0,0 +1,160 @@
+//! 4-dimensional vectors
+//!
+//! This module provides [`Vec4D`].
+
+use num::Num;
+
+use super::{Vec3D, VecN};
+
+/// A four-dimensional vector
+/// $\left[\begin{matrix}x&y&z&w\end{matrix}\right]$ backed by an integer type
+/// `I`
+///
+/// These are most often used as homogeneous coordinates for a [`Vec3D`], see
+/// [`Vec3D::to_homogeneous`] and [`Vec4D::from_homogeneous`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Vec4D<I>(pub I, pub I, pub I, pub I);
+
+impl<I> Vec4D<I> {
+    /// Create a [`Vec4D`] $\left[\begin{matrix}x&y&z&w\end{matrix}\right]$ from
+    /// a tuple $(x, y, z, w)$.
+    ///
+    /// Using the [`Vec4D`] constructor directly (e.g. `Vec4D(1, 2, 3, 4)`) is
+    /// preferred, however if you already have a tuple, this is easier.
+    ///
+    /// ```
+    /// use handyman::math::vector::Vec4D;
+    /// assert_eq!(Vec4D::from_tuple((1, 3, 5, 7)), Vec4D(1, 3, 5, 7));
+    /// ```
+    #[must_use]
+    pub fn from_tuple((x, y, z, w): (I, I, I, I)) -> Self {
+        Self(x, y, z, w)
+    }
+
+    /// Create a [`Vec4D`] by calling a function $f$ with the index of each
+    /// component, i.e.
+    /// $\left[\begin{matrix}f(0)&f(1)&f(2)&f(3)\end{matrix}\right]$.
+    pub fn from_fn<F>(mut f: F) -> Self
+    where
+        F: FnMut(usize) -> I,
+    {
+        Self(f(0), f(1), f(2), f(3))
+    }
+
+    /// Obtain the $x$ component from a vector
+    /// $\left[\begin{matrix}x&y&z&w\end{matrix}\right]$
+    #[must_use]
+    pub fn x(self) -> I {
+        self.0
+    }
+
+    /// Obtain the $y$ component from a vector
+    /// $\left[\begin{matrix}x&y&z&w\end{matrix}\right]$
+    #[must_use]
+    pub fn y(self) -> I {
+        self.1
+    }
+
+    /// Obtain the $z$ component from a vector
+    /// $\left[\begin{matrix}x&y&z&w\end{matrix}\right]$
+    #[must_use]
+    pub fn z(self) -> I {
+        self.2
+    }
+
+    /// Obtain the $w$ component from a vector
+    /// $\left[\begin{matrix}x&y&z&w\end{matrix}\right]$
+    #[must_use]
+    pub fn w(self) -> I {
+        self.3
+    }
+
+    /// Borrow each component of this vector, yielding a vector of references
+    pub const fn each_ref(&self) -> Vec4D<&I> {
+        Vec4D(&self.0, &self.1, &self.2, &self.3)
+    }
+
+    /// Mutably borrow each component of this vector, yielding a vector of
+    /// mutable references
+    pub const fn each_mut(&mut self) -> Vec4D<&mut I> {
+        Vec4D(&mut self.0, &mut self.1, &mut self.2, &mut self.3)
+    }
+
+    /// Apply a function $f$ onto every component of this vector
+    ///
+    /// For a vector $\vec
+    /// v=\left[\begin{matrix}v_x&v_y&v_z&v_w\end{matrix}\right]$, `v.apply(f)`
+    /// will return $\vec{v'}=\left[\begin{matrix}f(v_x)&f(v_y)&f(v_z)&f(v_w)
+    /// \end{matrix}\right]$.
+    pub fn apply<F, U>(self, mut f: F) -> Vec4D<U>
+    where
+        F: FnMut(I) -> U,
+    {
+        Vec4D(f(self.0), f(self.1), f(self.2), f(self.3))
+    }
+
+    /// Apply a function $f$ onto the corresponding components of two vectors
+    ///
+    /// For the vectors $\vec
+    /// a=\left[\begin{matrix}a_x&a_y&a_z&a_w\end{matrix}\right]$ and $\vec
+    /// b=\left[\begin{matrix}b_x&b_y&b_z&b_w\end{matrix}\right]$ and a function
+    /// $f(a, b)$, `a.zip_with(b, f)` will yield the vector $\vec
+    /// c=\left[\begin{matrix}f(a_x, b_x)&f(a_y, b_y)&f(a_z, b_z)&f(a_w, b_w)
+    /// \end{matrix}\right]$.
+    pub fn zip_with<F, O, U>(self, other: Vec4D<O>, mut f: F) -> Vec4D<U>
+    where
+        F: FnMut(I, O) -> U,
+    {
+        Vec4D(
+            f(self.0, other.0),
+            f(self.1, other.1),
+            f(self.2, other.2),
+            f(self.3, other.3),
+        )
+    }
+}
+
+impl<I: Num + Clone> Vec4D<I> {
+    /// Project these homogeneous coordinates
+    /// $\left[\begin{matrix}x&y&z&w\end{matrix}\right]$ back into the point
+    /// $\left[\begin{matrix}\frac xw&\frac yw&\frac zw\end{matrix}\right]$,
+    /// the same as [`Vec3D::from_homogeneous`]
+    ///
+    /// Returns [`None`] if $w=0$, i.e. if the coordinates describe a point at
+    /// infinity. Note that for integer components the division truncates.
+    ///
+    /// ```
+    /// use handyman::math::vector::{Vec3D, Vec4D};
+    /// assert_eq!(Vec4D(2, 4, 6, 2).from_homogeneous(), Some(Vec3D(1, 2, 3)));
+    /// assert_eq!(Vec4D(2, 4, 6, 0).from_homogeneous(), None);
+    /// ```
+    #[must_use]
+    #[allow(clippy::wrong_self_convention)] // it projects out of homogeneous coordinates
+    pub fn from_homogeneous(self) -> Option<Vec3D<I>> {
+        Vec3D::from_homogeneous(self)
+    }
+}
+
+impl<I> From<VecN<I, 4>> for Vec4D<I> {
+    fn from(VecN([x, y, z, w]): VecN<I, 4>) -> Self {
+        Self(x, y, z, w)
+    }
+}
+
+impl<I> From<Vec4D<I>> for VecN<I, 4> {
+    fn from(Vec4D(x, y, z, w): Vec4D<I>) -> Self {
+        Self([x, y, z, w])
+    }
+}
+
+impl<I> From<(I, I, I, I)> for Vec4D<I> {
+    fn from(tuple: (I, I, I, I)) -> Self {
+        Self::from_tuple(tuple)
+    }
+}
+
+impl<I> From<Vec4D<I>> for (I, I, I, I) {
+    fn from(Vec4D(x, y, z, w): Vec4D<I>) -> Self {
+        (x, y, z, w)
+    }
+}
@@ -0,0 +1,101 @@
+//! N-dimensional vectors
+//!
+//! This module provides [`VecN`].
+
+use std::array;
+
+/// An $N$-dimensional vector
+/// $\left[\begin{matrix}v_0&v_1&\cdots&v_{N-1}\end{matrix}\right]$ backed by an
+/// array of an integer type `I`
+///
+/// [`Vec2D`](super::Vec2D), [`Vec3D`](super::Vec3D) and
+/// [`Vec4D`](super::Vec4D) can be converted to and from [`VecN`] of the
+/// matching dimension with [`From`]/[`Into`].
+///
+/// ```
+/// use handyman::math::vector::{Vec2D, VecN};
+/// assert_eq!(VecN::from(Vec2D(1, 2)), VecN([1, 2]));
+/// assert_eq!(Vec2D::from(VecN([1, 2])), Vec2D(1, 2));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct VecN<I, const N: usize>(pub [I; N]);
+
+impl<I, const N: usize> VecN<I, N> {
+    /// Create a [`VecN`] by calling a function $f$ with the index of each
+    /// component, i.e. $\left[\begin{matrix}f(0)&f(1)&\cdots&f(N-1)
+    /// \end{matrix}\right]$.
+    ///
+    /// ```
+    /// use handyman::math::vector::VecN;
+    /// assert_eq!(VecN::from_fn(|i| i * 2), VecN([0, 2, 4, 6]));
+    /// ```
+    pub fn from_fn<F>(f: F) -> Self
+    where
+        F: FnMut(usize) -> I,
+    {
+        Self(array::from_fn(f))
+    }
+
+    /// Borrow each component of this vector, yielding a vector of references
+    ///
+    /// ```
+    /// use handyman::math::vector::VecN;
+    /// assert_eq!(VecN([1, 2, 3]).each_ref(), VecN([&1, &2, &3]));
+    /// ```
+    pub const fn each_ref(&self) -> VecN<&I, N> {
+        VecN(self.0.each_ref())
+    }
+
+    /// Mutably borrow each component of this vector, yielding a vector of
+    /// mutable references
+    pub const fn each_mut(&mut self) -> VecN<&mut I, N> {
+        VecN(self.0.each_mut())
+    }
+
+    /// Apply a function $f$ onto every component of this vector
+    ///
+    /// For a vector $\vec
+    /// v=\left[\begin{matrix}v_0&\cdots&v_{N-1}\end{matrix}\right]$,
+    /// `v.apply(f)` will return $\vec{v'}=\left[\begin{matrix}f(v_0)&\cdots&
+    /// f(v_{N-1})\end{matrix}\right]$.
+    ///
+    /// ```
+    /// use handyman::math::vector::VecN;
+    /// assert_eq!(VecN([1, 2, 3, 4]).apply(|x| x * 3), VecN([3, 6, 9, 12]));
+    /// ```
+    pub fn apply<F, U>(self, f: F) -> VecN<U, N>
+    where
+        F: FnMut(I) -> U,
+    {
+        VecN(self.0.map(f))
+    }
+
+    /// Apply a function $f$ onto the corresponding components of two vectors
+    ///
+    /// For the vectors $\vec
+    /// a=\left[\begin{matrix}a_0&\cdots&a_{N-1}\end{matrix}\right]$ and $\vec
+    /// b=\left[\begin{matrix}b_0&\cdots&b_{N-1}\end{matrix}\right]$ and a
+    /// function $f(a, b)$, `a.zip_with(b, f)` will yield the vector $\vec
+    /// c=\left[\begin{matrix}f(a_0, b_0)&\cdots&f(a_{N-1}, b_{N-1})
+    /// \end{matrix}\right]$.
+    ///
+    /// ```
+    /// use handyman::math::vector::VecN;
+    /// assert_eq!(
+    ///     VecN([1, 2, 3, 4]).zip_with(VecN([5, 6, 7, 8]), |a, b| a * b),
+    ///     VecN([5, 12, 21, 32])
+    /// );
+    /// ```
+    pub fn zip_with<F, O, U>(self, other: VecN<O, N>, mut f: F) -> VecN<U, N>
+    where
+        F: FnMut(I, O) -> U,
+    {
+        let mut other = other.0.into_iter();
+        self.apply(|lhs| {
+            other.next().map_or_else(
+                || unreachable!("both vectors have N components"),
+                |rhs| f(lhs, rhs),
+            )
+        })
+    }
+}